@@ -0,0 +1,77 @@
+//! Where fetched puzzle inputs are kept.
+//!
+//! Inputs are stored as `dayNN.txt`, the same layout `aoc all` reads, so
+//! the cache directory can be run as it is.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Error, Result};
+
+use crate::client::Client;
+
+/// The environment variable that overrides the cache directory.
+pub const CACHE_VAR: &str = "AOC_CACHE_DIR";
+
+/// The cache directory: `AOC_CACHE_DIR` if set, otherwise `aoc2020` under
+/// the user's cache directory.
+pub fn directory() -> Result<PathBuf> {
+    if let Some(directory) = env::var_os(CACHE_VAR) {
+        return Ok(PathBuf::from(directory));
+    }
+
+    let base = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .ok_or_else(|| Error::config(format!("no cache directory; set {} or HOME", CACHE_VAR)))?;
+
+    Ok(base.join("aoc2020"))
+}
+
+/// Where the input for `day` is kept in `directory`.
+pub fn input_path(directory: &Path, day: u32) -> PathBuf {
+    directory.join(format!("day{:02}.txt", day))
+}
+
+/// The cached input for `day`, which must already have been fetched.
+pub fn cached_input(day: u32) -> Result<PathBuf> {
+    let path = input_path(&directory()?, day);
+    if path.exists() {
+        Ok(path)
+    } else {
+        Err(Error::config(format!(
+            "no input given and none cached at {}; run `aoc fetch {}` first",
+            path.display(),
+            day
+        )))
+    }
+}
+
+/// Fetches the input for `day` into `directory`, unless it is already there
+/// and `refresh` is false.
+///
+/// Returns the path of the input and whether it was downloaded.
+pub fn fetch(
+    client: &Client,
+    directory: &Path,
+    day: u32,
+    refresh: bool,
+) -> Result<(PathBuf, bool)> {
+    let path = input_path(directory, day);
+    if path.exists() && !refresh {
+        return Ok((path, false));
+    }
+
+    let input = client.input(day)?;
+
+    // Written aside and renamed into place, so an interrupted download
+    // never leaves a partial input behind to be mistaken for a real one.
+    let partial = path.with_extension("part");
+    fs::create_dir_all(directory)
+        .and_then(|_| fs::write(&partial, input))
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|e| Error::from(e).file(&path.to_string_lossy()))?;
+
+    Ok((path, true))
+}
@@ -0,0 +1,142 @@
+//! A client for the Advent of Code website.
+
+use std::env;
+use std::time::Duration;
+
+use aoc_common::{Error, Result};
+
+/// The puzzle year every request is for.
+pub const YEAR: u32 = 2020;
+
+/// The site requests go to unless `AOC_URL` says otherwise.
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session cookie from a logged-in
+/// browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable that points requests at another site, such as
+/// a local stand-in.
+pub const URL_VAR: &str = "AOC_URL";
+
+/// The site asks automated tools to say who they are.
+const USER_AGENT: &str = concat!(
+    "aoc2020/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/jeamland/aoc2020)"
+);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(TIMEOUT)
+                .build(),
+        }
+    }
+
+    /// Creates a client from `AOC_SESSION` and, if set, `AOC_URL`.
+    pub fn from_env() -> Result<Self> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or_else(|| {
+                Error::config(format!(
+                    "{} must be set to the session cookie of a logged-in browser",
+                    SESSION_VAR
+                ))
+            })?;
+        let base_url = env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_string());
+
+        Ok(Self::new(&base_url, session.trim()))
+    }
+
+    /// The URL of a day's puzzle page, which other requests are relative to.
+    fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    /// Starts a request carrying the session cookie.
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    /// Downloads the puzzle input for `day`.
+    pub fn input(&self, day: u32) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        body(&url, self.request("GET", &url).call())
+    }
+
+    /// Submits `answer` for a part of `day`, returning the site's reply as
+    /// plain text.
+    pub fn answer(&self, day: u32, part: u32, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        let page = body(
+            &url,
+            self.request("POST", &url)
+                .send_form(&[("level", &level), ("answer", answer)]),
+        )?;
+
+        Ok(article_text(&page))
+    }
+}
+
+/// The text of a page's `<article>`, which is where the site puts its
+/// reply, with the markup stripped and the spacing tidied.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(page, |(_, rest)| {
+            rest.split_once("</article>").map_or(rest, |(a, _)| a)
+        });
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the body of a successful response, or turns a failed one into an
+/// error that includes the first line the site gave as its reason.
+fn body(url: &str, response: std::result::Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| Error::remote(format!("{}: {}", url, e))),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            let reason = body.lines().next().unwrap_or("").trim();
+            Err(Error::remote(if reason.is_empty() {
+                format!("{}: HTTP {}", url, status)
+            } else {
+                format!("{}: HTTP {}: {}", url, status, reason)
+            }))
+        }
+        Err(ureq::Error::Transport(transport)) => {
+            Err(Error::remote(format!("{}: {}", url, transport)))
+        }
+    }
+}
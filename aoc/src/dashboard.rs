@@ -0,0 +1,529 @@
+//! A full-screen terminal dashboard for browsing and running days.
+//!
+//! Each day's input defaults to the one in the input directory, and can be
+//! changed to any file. Runs happen on a thread of their own so that the
+//! dashboard stays responsive during the slow days. Days that draw their
+//! working, such as day 11 and day 17, are drawn on one too, and can then
+//! be stepped through frame by frame.
+//!
+//! While the dashboard has the terminal, log messages and the panics of
+//! those threads are held back, and written out once it closes.
+
+use std::io::{self, IsTerminal};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::{Error, Frame, Part, Report, Result, Stats};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
+
+use crate::{cache, Options, DAYS};
+
+/// How long to wait for a key before checking on a running day.
+const TICK: Duration = Duration::from_millis(100);
+
+/// The name of the threads days are run and drawn on.
+const WORKER: &str = "dashboard";
+
+/// A day's row on the dashboard.
+struct Day {
+    day: u32,
+    input: Option<PathBuf>,
+    /// The last run's report, or the error that stopped it.
+    outcome: Option<std::result::Result<Report, String>>,
+}
+
+/// What a day is being run in the background for.
+#[derive(Clone, Copy)]
+enum Job {
+    Solve,
+    /// Drawing the frames of a part.
+    Frames(Part),
+}
+
+/// What a background job sends back.
+enum Finished {
+    Report(Box<std::result::Result<Report, String>>),
+    Frames(std::result::Result<Vec<Frame>, String>),
+}
+
+/// A day being run in the background.
+struct Run {
+    index: usize,
+    job: Job,
+    receiver: Receiver<Finished>,
+}
+
+enum Mode {
+    Days,
+    /// Editing the selected day's input path.
+    Input(String),
+    Frames {
+        title: String,
+        frames: Vec<Frame>,
+        index: usize,
+    },
+}
+
+pub struct Dashboard {
+    days: Vec<Day>,
+    selected: usize,
+    part: Part,
+    options: Options,
+    mode: Mode,
+    running: Option<Run>,
+    message: String,
+}
+
+fn part_name(part: Part) -> &'static str {
+    match part {
+        Part::One => "part 1",
+        Part::Two => "part 2",
+        Part::Both => "both parts",
+    }
+}
+
+fn median(stats: Option<Stats>) -> String {
+    stats.map_or_else(String::new, |stats| format!("{:.2?}", stats.median))
+}
+
+fn read_lines(path: &Path) -> Result<Vec<String>> {
+    aoc_common::read_lines_from(&path.to_string_lossy())
+}
+
+impl Dashboard {
+    /// A dashboard with each day's input taken from `directory`, where
+    /// there is one.
+    pub fn new(directory: &Path, options: Options) -> Self {
+        let days = (1..=DAYS)
+            .map(|day| {
+                let path = cache::input_path(directory, day);
+                Day {
+                    day,
+                    input: Some(path).filter(|path| path.exists()),
+                    outcome: None,
+                }
+            })
+            .collect();
+
+        Self {
+            days,
+            selected: 0,
+            part: Part::Both,
+            options,
+            mode: Mode::Days,
+            running: None,
+            message: String::new(),
+        }
+    }
+
+    /// The day the cursor is on.
+    pub fn selected(&self) -> u32 {
+        self.days[self.selected].day
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    /// The frame being shown, when stepping through a day.
+    pub fn frame(&self) -> Option<&Frame> {
+        match &self.mode {
+            Mode::Frames { frames, index, .. } => frames.get(*index),
+            _ => None,
+        }
+    }
+
+    /// The line at the bottom that says what last happened.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Acts on a key press, returning whether it asked to quit.
+    pub fn key(&mut self, code: KeyCode) -> bool {
+        match &mut self.mode {
+            Mode::Days => return self.days_key(code),
+            Mode::Input(text) => match code {
+                KeyCode::Char(c) => text.push(c),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Enter => {
+                    let text = text.trim().to_string();
+                    let day = &mut self.days[self.selected];
+                    day.input = Some(PathBuf::from(&text)).filter(|_| !text.is_empty());
+                    day.outcome = None;
+                    self.message = match &day.input {
+                        Some(path) if !path.exists() => format!("{} does not exist", text),
+                        Some(_) => format!("Day {} will read {}", day.day, text),
+                        None => format!("Day {} has no input", day.day),
+                    };
+                    self.mode = Mode::Days;
+                }
+                KeyCode::Esc => self.mode = Mode::Days,
+                _ => {}
+            },
+            Mode::Frames { frames, index, .. } => match code {
+                KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+                    *index = (*index + 1).min(frames.len() - 1)
+                }
+                KeyCode::Left | KeyCode::Char('h') => *index = index.saturating_sub(1),
+                KeyCode::Home | KeyCode::Char('g') => *index = 0,
+                KeyCode::End | KeyCode::Char('G') => *index = frames.len() - 1,
+                KeyCode::Esc | KeyCode::Char('q') => self.mode = Mode::Days,
+                _ => {}
+            },
+        }
+
+        false
+    }
+
+    fn days_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.days.len() - 1)
+            }
+            KeyCode::Char('1') => self.part = Part::One,
+            KeyCode::Char('2') => self.part = Part::Two,
+            KeyCode::Char('b') => self.part = Part::Both,
+            KeyCode::Enter | KeyCode::Char('r') => self.start(),
+            KeyCode::Char('i') => {
+                let input = &self.days[self.selected].input;
+                let text = input
+                    .as_ref()
+                    .map_or_else(String::new, |path| path.to_string_lossy().into_owned());
+                self.mode = Mode::Input(text);
+            }
+            KeyCode::Char('f') => self.show_frames(),
+            _ => {}
+        }
+
+        false
+    }
+
+    /// Starts `job` for the selected day on a thread of its own, unless a
+    /// day is running already. `work` is given the day's input.
+    fn spawn<F>(&mut self, job: Job, work: F)
+    where
+        F: FnOnce(u32, Vec<String>) -> Finished + Send + 'static,
+    {
+        let day = &self.days[self.selected];
+        if self.running.is_some() {
+            self.message = String::from("Wait for the running day to finish");
+            return;
+        }
+        let path = match &day.input {
+            Some(path) => path.clone(),
+            None => {
+                self.message = format!("Day {} has no input; press i to choose one", day.day);
+                return;
+            }
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let number = day.day;
+        let spawned = thread::Builder::new()
+            .name(String::from(WORKER))
+            .spawn(move || {
+                let finished = match read_lines(&path) {
+                    Ok(lines) => work(number, lines),
+                    Err(error) => {
+                        let error = error.file(&path.to_string_lossy()).to_string();
+                        match job {
+                            Job::Solve => Finished::Report(Box::new(Err(error))),
+                            Job::Frames(_) => Finished::Frames(Err(error)),
+                        }
+                    }
+                };
+                let _ = sender.send(finished);
+            });
+        if let Err(error) = spawned {
+            self.message = format!("Day {}: {}", number, error);
+            return;
+        }
+
+        self.message = match job {
+            Job::Solve => format!("Running day {}, {}", number, part_name(self.part)),
+            Job::Frames(part) => format!("Drawing day {}, {}", number, part_name(part)),
+        };
+        self.running = Some(Run {
+            index: self.selected,
+            job,
+            receiver,
+        });
+    }
+
+    /// Starts running the selected day.
+    fn start(&mut self) {
+        let part = self.part;
+        let options = self.options;
+        let path = self.days[self.selected].input.clone().unwrap_or_default();
+
+        self.spawn(Job::Solve, move |day, lines| {
+            Finished::Report(Box::new(
+                crate::solver(day, &options)
+                    .report(day, &lines, part, 1)
+                    .map_err(|e| e.file(&path.to_string_lossy()).to_string()),
+            ))
+        });
+    }
+
+    /// Collects the result of a run that has finished.
+    pub fn poll(&mut self) {
+        let run = match &self.running {
+            Some(run) => run,
+            None => return,
+        };
+
+        let finished = match run.receiver.try_recv() {
+            Ok(finished) => finished,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                let panicked = String::from("the solver panicked");
+                match run.job {
+                    Job::Solve => Finished::Report(Box::new(Err(panicked))),
+                    Job::Frames(_) => Finished::Frames(Err(panicked)),
+                }
+            }
+        };
+
+        let (index, job) = (run.index, run.job);
+        self.running = None;
+        let day = &mut self.days[index];
+
+        match (finished, job) {
+            (Finished::Report(outcome), _) => {
+                self.message = match &*outcome {
+                    Ok(_) => format!("Day {} finished", day.day),
+                    Err(error) => format!("Day {}: {}", day.day, error),
+                };
+                day.outcome = Some(*outcome);
+            }
+            (Finished::Frames(Ok(frames)), Job::Frames(part)) if frames.is_empty() => {
+                self.message = format!("Day {} draws nothing for {}", day.day, part_name(part))
+            }
+            (Finished::Frames(Ok(frames)), Job::Frames(part)) => {
+                self.message = format!("Day {} drawn", day.day);
+                // Only step through them if nothing else was started since.
+                if let Mode::Days = self.mode {
+                    self.mode = Mode::Frames {
+                        title: format!("Day {}, {}", day.day, part_name(part)),
+                        frames,
+                        index: 0,
+                    }
+                }
+            }
+            (Finished::Frames(Ok(_)), Job::Solve) => {}
+            (Finished::Frames(Err(error)), _) => {
+                self.message = format!("Day {}: {}", day.day, error)
+            }
+        }
+    }
+
+    /// Starts drawing the selected day's frames, to step through once they
+    /// are ready.
+    fn show_frames(&mut self) {
+        let part = match self.part {
+            Part::Both => Part::One,
+            part => part,
+        };
+        let path = self.days[self.selected].input.clone().unwrap_or_default();
+
+        self.spawn(Job::Frames(part), move |day, lines| {
+            Finished::Frames(
+                crate::frames(day, &lines, part)
+                    .unwrap_or_else(|| Ok(Vec::new()))
+                    .map_err(|e| e.file(&path.to_string_lossy()).to_string()),
+            )
+        });
+    }
+
+    fn status(&self, index: usize) -> &'static str {
+        let day = &self.days[index];
+        match (&self.running, &day.outcome, &day.input) {
+            (Some(run), _, _) if run.index == index => match run.job {
+                Job::Solve => "running",
+                Job::Frames(_) => "drawing",
+            },
+            (_, Some(Ok(_)), _) => "solved",
+            (_, Some(Err(_)), _) => "error",
+            (_, None, Some(_)) => "ready",
+            (_, None, None) => "no input",
+        }
+    }
+
+    fn days_table(&self) -> Table<'_> {
+        let header = Row::new(vec![
+            "Day",
+            "Status",
+            "Input",
+            "Part 1",
+            "Part 2",
+            "Parse",
+            "Part 1 time",
+            "Part 2 time",
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows = self.days.iter().enumerate().map(|(index, day)| {
+            let input = day
+                .input
+                .as_ref()
+                .map_or_else(String::new, |path| path.display().to_string());
+            let mut cells = vec![
+                Cell::from(day.day.to_string()),
+                Cell::from(self.status(index)),
+                Cell::from(input),
+            ];
+            cells.extend(match &day.outcome {
+                Some(Ok(report)) => vec![
+                    report.answers.part1.clone().unwrap_or_default(),
+                    report.answers.part2.clone().unwrap_or_default(),
+                    median(Some(report.timings.parse)),
+                    median(report.timings.part1),
+                    median(report.timings.part2),
+                ]
+                .into_iter()
+                .map(Cell::from)
+                .collect(),
+                _ => Vec::new(),
+            });
+            Row::new(cells)
+        });
+
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(11),
+            Constraint::Length(11),
+            Constraint::Length(11),
+        ];
+
+        Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" Advent of Code 2020: {} ", part_name(self.part))),
+            )
+    }
+
+    fn help(&self) -> &'static str {
+        match self.mode {
+            Mode::Days => {
+                "up/down: choose day  1/2/b: choose part  enter: run  i: input  f: frames  q: quit"
+            }
+            Mode::Input(_) => "enter: use this input  esc: cancel",
+            Mode::Frames { .. } => "left/right: step  home/end: first/last  esc: back",
+        }
+    }
+
+    /// Draws the dashboard onto `screen`.
+    pub fn draw(&self, screen: &mut ratatui::Frame<'_>) {
+        let [main, status, help] = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(screen.area());
+
+        match &self.mode {
+            Mode::Frames {
+                title,
+                frames,
+                index,
+            } => {
+                let frame = &frames[*index];
+                let block = Block::default().borders(Borders::ALL).title(format!(
+                    " {}: {} ({} of {}) ",
+                    title,
+                    frame.title,
+                    index + 1,
+                    frames.len()
+                ));
+                screen.render_widget(Paragraph::new(frame.picture.as_str()).block(block), main);
+            }
+            _ => {
+                let mut state = TableState::default().with_selected(Some(self.selected));
+                screen.render_stateful_widget(self.days_table(), main, &mut state);
+            }
+        }
+
+        let status_line = match &self.mode {
+            Mode::Input(text) => format!("Input for day {}: {}_", self.selected(), text),
+            _ => self.message.clone(),
+        };
+        screen.render_widget(Line::from(status_line), status);
+        screen.render_widget(
+            Line::from(self.help()).style(Style::default().add_modifier(Modifier::DIM)),
+            help,
+        );
+    }
+
+    /// Takes over the terminal until the dashboard is quit.
+    pub fn run(mut self) -> Result<()> {
+        if !io::stdout().is_terminal() {
+            return Err(Error::config("the dashboard needs a terminal"));
+        }
+        let mut terminal = ratatui::try_init()?;
+
+        // Anything written to standard error now would land on the screen,
+        // so hold it back, panics on the day threads included.
+        aoc_common::hold_log();
+        let previous = Arc::new(panic::take_hook());
+        let hook = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() == Some(WORKER) {
+                aoc_common::write_log(format_args!("{}", info));
+            } else {
+                hook(info);
+            }
+        }));
+
+        let result = loop {
+            if let Err(e) = terminal.draw(|screen| self.draw(screen)) {
+                break Err(e);
+            }
+
+            match event::poll(TICK) {
+                Ok(true) => match event::read() {
+                    Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                        if self.key(key.code) {
+                            break Ok(());
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => break Err(e),
+                },
+                Ok(false) => {}
+                Err(e) => break Err(e),
+            }
+
+            self.poll();
+        };
+
+        ratatui::restore();
+        drop(panic::take_hook());
+        if let Ok(previous) = Arc::try_unwrap(previous) {
+            panic::set_hook(previous);
+        }
+        for message in aoc_common::release_log() {
+            eprintln!("{}", message);
+        }
+
+        result.map_err(Into::into)
+    }
+}
@@ -0,0 +1,11 @@
+pub mod cache;
+pub mod client;
+pub mod dashboard;
+pub mod page;
+pub mod plugins;
+pub mod serve;
+pub mod submit;
+
+pub use aoc_days::{
+    differentials, frames, fuzz_seeds, fuzz_target, generator, run, solver, Options, DAYS,
+};
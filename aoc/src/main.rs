@@ -0,0 +1,768 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc::cache;
+use aoc::client::Client;
+use aoc::dashboard::Dashboard;
+use aoc::page::{self, Entry, PageFormat};
+use aoc::plugins::{self, Plugin};
+use aoc::serve::{self, Server};
+use aoc::submit::{self, History, Outcome};
+use aoc::{Options, DAYS};
+use aoc_common::serde_json::Value;
+use aoc_common::{docs, fuzz};
+use aoc_common::{info, Answers, Error, Format, Part, Rng, Solver};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+
+/// The `DAY` argument, for a day up to `last`.
+fn day_arg(last: u32) -> Arg<'static, 'static> {
+    Arg::with_name("DAY")
+        .help("Day number")
+        .required(true)
+        .index(1)
+        .validator(move |v| match u32::from_str(&v) {
+            Ok(day) if (1..=last).contains(&day) => Ok(()),
+            _ => Err(format!("day must be between 1 and {}", last)),
+        })
+}
+
+fn preamble_arg() -> Arg<'static, 'static> {
+    Arg::with_name("PREAMBLE")
+        .long("preamble")
+        .help("Preamble length for day 9")
+        .takes_value(true)
+        .validator(|v| usize::from_str(&v).map(|_| ()).map_err(|e| e.to_string()))
+}
+
+/// The `INPUT` argument for commands that fall back to the cached input.
+fn cached_input_arg(index: u64) -> Arg<'static, 'static> {
+    Arg::with_name("INPUT")
+        .index(index)
+        .help("Input file name, or - for standard input [default: the cached input]")
+}
+
+fn plugins_arg() -> Arg<'static, 'static> {
+    Arg::with_name("PLUGINS")
+        .long("plugins")
+        .value_name("DIRECTORY")
+        .help("Directory of day plugins [default: $AOC_PLUGIN_DIR]")
+        .takes_value(true)
+}
+
+/// Loads the plugins in the directory given by `--plugins` or
+/// `AOC_PLUGIN_DIR`, if either is.
+fn load_plugins(matches: &ArgMatches) -> aoc_common::Result<Vec<Plugin>> {
+    let directory = match matches.value_of("PLUGINS") {
+        Some(directory) => Some(PathBuf::from(directory)),
+        None => plugins::directory(),
+    };
+
+    match directory {
+        Some(directory) => plugins::discover(&directory),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Takes the plugin to use for `day` out of `plugins`: the one called
+/// `name` if a name is given, or else the first for a day with no built-in
+/// solver.
+fn take_plugin(
+    plugins: &mut Vec<Plugin>,
+    day: u32,
+    name: Option<&str>,
+) -> aoc_common::Result<Option<Plugin>> {
+    let found = plugins
+        .iter()
+        .position(|plugin| plugin.day == day && name.is_none_or(|name| plugin.name == name));
+
+    match (found, name) {
+        (Some(index), Some(_)) => Ok(Some(plugins.remove(index))),
+        (_, Some(name)) => Err(Error::config(format!(
+            "no plugin named {} for day {}",
+            name, day
+        ))),
+        _ if day <= DAYS => Ok(None),
+        (Some(index), None) => Ok(Some(plugins.remove(index))),
+        (None, None) => Err(Error::config(format!(
+            "no solver for day {}; add a plugin for it to the plugin directory",
+            day
+        ))),
+    }
+}
+
+/// The solver for `day`, which is `plugin` if there is one, and the parts
+/// it can run of those `requested`.
+fn choose(
+    day: u32,
+    requested: Part,
+    options: &Options,
+    plugin: Option<Plugin>,
+) -> (Box<dyn Solver>, Part) {
+    match plugin {
+        Some(plugin) => {
+            let part = plugin.part(requested);
+            (Box::new(plugin), part)
+        }
+        None => (aoc::solver(day, options), requested),
+    }
+}
+
+fn number_arg(name: &'static str, long: &'static str, help: &'static str) -> Arg<'static, 'static> {
+    Arg::with_name(name)
+        .long(long)
+        .value_name("N")
+        .help(help)
+        .takes_value(true)
+        .validator(|v| u64::from_str(&v).map(|_| ()).map_err(|e| e.to_string()))
+}
+
+fn options(matches: &ArgMatches) -> Options {
+    let mut options = Options::default();
+
+    if let Some(v) = matches.value_of("PREAMBLE") {
+        options.preamble = usize::from_str(v).unwrap();
+    }
+
+    options
+}
+
+fn solve(
+    day: u32,
+    lines: &[String],
+    matches: &ArgMatches,
+    options: &Options,
+    plugin: Option<Plugin>,
+) -> aoc_common::Result<()> {
+    let runs = aoc_common::bench_runs(matches);
+    let built_in = plugin.is_none();
+    let (solver, part) = choose(day, aoc_common::part(matches), options, plugin);
+
+    match aoc_common::format(matches) {
+        Format::Json => {
+            let report = solver.report(day, lines, part, runs.unwrap_or(1))?;
+            println!("{}", report.to_json());
+        }
+        Format::Human => match runs {
+            Some(runs) => print!("{}", solver.bench(lines, part, runs)?),
+            None if built_in => aoc::run(day, lines, part, options)?,
+            None => {
+                let answers = solver.solve(lines, part)?;
+                if let Some(answer) = answers.part1 {
+                    println!("Part 1: {}", answer);
+                }
+                if let Some(answer) = answers.part2 {
+                    println!("Part 2: {}", answer);
+                }
+            }
+        },
+    }
+
+    Ok(())
+}
+
+fn day(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let day = u32::from_str(matches.value_of("DAY").unwrap()).unwrap();
+    let plugin = take_plugin(&mut load_plugins(matches)?, day, matches.value_of("SOLVER"))?;
+    let path = match matches.value_of("INPUT") {
+        Some(path) => path.to_string(),
+        None => cache::cached_input(day)?.to_string_lossy().into_owned(),
+    };
+    let lines = aoc_common::read_lines_from(&path)?;
+
+    solve(day, &lines, matches, &options(matches), plugin).map_err(|e| e.file(&path))
+}
+
+fn all(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let directory = match matches.value_of("DIRECTORY") {
+        Some(directory) => PathBuf::from(directory),
+        None => cache::directory()?,
+    };
+    let part = aoc_common::part(matches);
+    let options = options(matches);
+    let runs = aoc_common::bench_runs(matches);
+    let format = aoc_common::format(matches);
+    let mut plugins = load_plugins(matches)?;
+    let last = plugins.iter().map(|plugin| plugin.day).fold(DAYS, u32::max);
+    let mut reports = Vec::new();
+    let mut exit_code = None;
+
+    for day in 1..=last {
+        let plugin = match take_plugin(&mut plugins, day, None) {
+            Ok(plugin) => plugin,
+            Err(_) => continue,
+        };
+
+        let path = cache::input_path(&directory, day);
+        if !path.exists() {
+            info!("Day {}: no input at {}", day, path.display());
+            continue;
+        }
+
+        // An input that cannot be read fails its own day, not the rest.
+        let path = path.to_string_lossy();
+        let result = aoc_common::read_lines_from(&path).and_then(|lines| {
+            if format == Format::Json {
+                let (solver, part) = choose(day, part, &options, plugin);
+                solver
+                    .report(day, &lines, part, runs.unwrap_or(1))
+                    .map(|report| reports.push(report.to_json()))
+            } else {
+                println!("Day {}:", day);
+                let result = solve(day, &lines, matches, &options, plugin);
+                println!();
+                result
+            }
+        });
+
+        if let Err(error) = result {
+            exit_code.get_or_insert(error.exit_code());
+            eprintln!("Day {}: error: {}", day, error.file(&path));
+        }
+    }
+
+    if format == Format::Json {
+        println!("{}", Value::Array(reports));
+    }
+
+    if let Some(code) = exit_code {
+        process::exit(code);
+    }
+
+    Ok(())
+}
+
+fn generate(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let day = u32::from_str(matches.value_of("DAY").unwrap()).unwrap();
+    let options = options(matches);
+    let generator = aoc::generator(day, &options);
+    let seed = matches
+        .value_of("SEED")
+        .map_or(0, |v| u64::from_str(v).unwrap());
+    let size = matches
+        .value_of("SIZE")
+        .map_or(generator.default_size(), |v| usize::from_str(v).unwrap());
+
+    let generated = generator.generate(&mut Rng::new(seed), size);
+
+    let mut expected = String::new();
+    if let Some(answer) = &generated.answers.part1 {
+        expected += &format!("part1: {}\n", answer);
+    }
+    if let Some(answer) = &generated.answers.part2 {
+        expected += &format!("part2: {}\n", answer);
+    }
+    if day == 9 && options.preamble != Options::default().preamble && !expected.is_empty() {
+        expected += &format!("preamble: {}\n", options.preamble);
+    }
+
+    match matches.value_of("OUTPUT") {
+        Some(path) => {
+            let mut input = generated.lines.join("\n");
+            input.push('\n');
+            fs::write(path, input).map_err(|e| Error::from(e).file(path))?;
+
+            // Planted answers go alongside, where the fixture tests find them.
+            if !expected.is_empty() {
+                let sidecar = Path::new(path).with_extension("expected");
+                fs::write(&sidecar, expected)
+                    .map_err(|e| Error::from(e).file(&sidecar.to_string_lossy()))?;
+            }
+        }
+        None => {
+            for line in &generated.lines {
+                println!("{}", line);
+            }
+            if !expected.is_empty() {
+                info!("{}", expected.trim_end());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn differential(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let value = |name, default| {
+        matches
+            .value_of(name)
+            .map_or(default, |v| u64::from_str(v).unwrap())
+    };
+    let seed = value("SEED", 0);
+    let runs = value("RUNS", 1000);
+    let size = value("SIZE", 12) as usize;
+    let mut disagreed = 0;
+
+    let checks = aoc::differentials();
+    for check in &checks {
+        match check.check(seed..seed.saturating_add(runs), size) {
+            Some(disagreement) => {
+                disagreed += 1;
+                println!("{}", disagreement);
+            }
+            None => println!("{}: ok", check.name()),
+        }
+    }
+
+    if disagreed > 0 {
+        return Err(Error::failed(format!(
+            "{} of {} pairs disagreed",
+            disagreed,
+            checks.len()
+        )));
+    }
+
+    Ok(())
+}
+
+fn fuzz(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let day = u32::from_str(matches.value_of("DAY").unwrap()).unwrap();
+    let value = |name, default| {
+        matches
+            .value_of(name)
+            .map_or(default, |v| u64::from_str(v).unwrap())
+    };
+    let seed = value("SEED", 0);
+    let runs = value("RUNS", 10000) as usize;
+    let timeout = Duration::from_secs(value("TIMEOUT", 1));
+
+    let target = aoc::fuzz_target(day);
+    let crash = match fuzz::fuzz(
+        &target,
+        &aoc::fuzz_seeds(day),
+        &mut Rng::new(seed),
+        runs,
+        timeout,
+    ) {
+        Some(crash) => crash,
+        None => {
+            info!("Day {}: no failures in {} runs", day, runs);
+            return Ok(());
+        }
+    };
+
+    println!("Day {}: {}", day, crash.failure);
+    println!("{:?}", String::from_utf8_lossy(&crash.input));
+
+    // Named after the contents, so finding the same input twice does not
+    // add it to the corpus twice.
+    if let Some(directory) = matches.value_of("CORPUS") {
+        let mut hasher = DefaultHasher::new();
+        crash.input.hash(&mut hasher);
+        let path = Path::new(directory).join(format!("day{:02}-{:016x}.txt", day, hasher.finish()));
+        fs::write(&path, &crash.input).map_err(|e| Error::from(e).file(&path.to_string_lossy()))?;
+        println!("Saved to {}", path.display());
+    }
+
+    Err(Error::failed(format!(
+        "day {} failed on a fuzzed input",
+        day
+    )))
+}
+
+fn fetch(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let day = u32::from_str(matches.value_of("DAY").unwrap()).unwrap();
+    let client = Client::from_env()?;
+    let directory = cache::directory()?;
+
+    let (path, fetched) = cache::fetch(&client, &directory, day, matches.is_present("REFRESH"))?;
+    if fetched {
+        info!("Day {}: fetched into {}", day, path.display());
+    } else {
+        info!("Day {}: already cached at {}", day, path.display());
+    }
+
+    Ok(())
+}
+
+fn submit(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let day = u32::from_str(matches.value_of("DAY").unwrap()).unwrap();
+    let part = u32::from_str(matches.value_of("PART").unwrap()).unwrap();
+    let path = match matches.value_of("INPUT") {
+        Some(path) => path.to_string(),
+        None => cache::cached_input(day)?.to_string_lossy().into_owned(),
+    };
+    let lines = aoc_common::read_lines_from(&path)?;
+
+    let (only, pick): (Part, fn(Answers) -> Option<String>) = match part {
+        1 => (Part::One, |answers| answers.part1),
+        _ => (Part::Two, |answers| answers.part2),
+    };
+    let answers = aoc::solver(day, &options(matches))
+        .solve(&lines, only)
+        .map_err(|e| e.file(&path))?;
+    let answer = pick(answers).unwrap();
+
+    let client = Client::from_env()?;
+    let mut history = History::load(&cache::directory()?.join(History::FILE_NAME))?;
+    let attempt = submit::submit(&client, &mut history, day, part, &answer, submit::now())?;
+
+    println!(
+        "Day {} part {}: {} is {}",
+        day, part, answer, attempt.outcome
+    );
+    if attempt.outcome != Outcome::Correct {
+        info!("{}", attempt.message);
+        return Err(Error::failed("the answer was not accepted"));
+    }
+
+    Ok(())
+}
+
+fn report(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let directory = match matches.value_of("DIRECTORY") {
+        Some(directory) => PathBuf::from(directory),
+        None => cache::directory()?,
+    };
+    let options = options(matches);
+    let runs = matches
+        .value_of("RUNS")
+        .map_or(1, |v| usize::from_str(v).unwrap())
+        .max(1);
+    let format = match matches.value_of("FORMAT") {
+        Some("html") => PageFormat::Html,
+        _ => PageFormat::Markdown,
+    };
+    let mut entries = Vec::new();
+
+    for day in 1..=DAYS {
+        let path = cache::input_path(&directory, day);
+        if !path.exists() {
+            continue;
+        }
+
+        let path = path.to_string_lossy().into_owned();
+        let input_bytes = fs::metadata(&path).map_or(0, |metadata| metadata.len());
+        let (input_lines, outcome) = match aoc_common::read_lines_from(&path) {
+            Ok(lines) => (
+                lines.len(),
+                aoc::solver(day, &options)
+                    .report(day, &lines, Part::Both, runs)
+                    .map_err(|e| e.file(&path).to_string()),
+            ),
+            Err(error) => (0, Err(error.file(&path).to_string())),
+        };
+
+        entries.push(Entry {
+            day,
+            input_lines,
+            input_bytes,
+            outcome,
+        });
+    }
+
+    let page = page::render(format, &entries, DAYS);
+    match matches.value_of("OUTPUT") {
+        Some(path) => fs::write(path, page).map_err(|e| Error::from(e).file(path))?,
+        None => print!("{}", page),
+    }
+
+    Ok(())
+}
+
+fn dashboard(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let directory = match matches.value_of("DIRECTORY") {
+        Some(directory) => PathBuf::from(directory),
+        None => cache::directory()?,
+    };
+
+    Dashboard::new(&directory, options(matches)).run()
+}
+
+fn list_plugins(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let plugins = load_plugins(matches)?;
+    if plugins.is_empty() {
+        info!("No plugins found");
+    }
+
+    for plugin in &plugins {
+        let parts = match (plugin.solves(1), plugin.solves(2)) {
+            (true, true) => "parts 1 and 2",
+            (true, false) => "part 1",
+            _ => "part 2",
+        };
+        println!(
+            "Day {}: {} ({}) -- {} -- {}",
+            plugin.day,
+            plugin.name,
+            parts,
+            plugin.input_format,
+            plugin.path.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn serve(matches: &ArgMatches) -> aoc_common::Result<()> {
+    let address = matches.value_of("ADDRESS").unwrap();
+    let value = |name, default| {
+        matches
+            .value_of(name)
+            .map_or(default, |v| u64::from_str(v).unwrap())
+    };
+    let config = serve::Config {
+        timeout: Duration::from_secs(value("TIMEOUT", 10)),
+        max_running: value("JOBS", 4).max(1) as usize,
+        workers: value("WORKERS", 8).max(1) as usize,
+    };
+
+    let server = Server::bind(address, config)?;
+    if let Some(address) = server.local_addr() {
+        info!("Listening on http://{}", address);
+    }
+    server.run();
+
+    Ok(())
+}
+
+fn main() {
+    let app = App::new("AOC2020")
+        .bin_name("aoc")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("day")
+                .about("Runs a single day")
+                .arg(day_arg(plugins::LAST_DAY))
+                .arg(cached_input_arg(2))
+                .arg(plugins_arg())
+                .arg(
+                    Arg::with_name("SOLVER")
+                        .long("solver")
+                        .value_name("NAME")
+                        .help("Use the plugin with this name instead of the built-in solver")
+                        .takes_value(true),
+                )
+                .arg(aoc_common::part_arg())
+                .arg(aoc_common::bench_arg())
+                .arg(aoc_common::format_arg())
+                .arg(preamble_arg())
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("all")
+                .about("Runs every day with an input in a directory")
+                .arg(
+                    Arg::with_name("DIRECTORY")
+                        .help("Directory containing dayNN.txt inputs [default: the cache]")
+                        .index(1),
+                )
+                .arg(plugins_arg())
+                .arg(aoc_common::part_arg())
+                .arg(aoc_common::bench_arg())
+                .arg(aoc_common::format_arg())
+                .arg(preamble_arg())
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("dashboard")
+                .about("Browses and runs days in a full-screen terminal interface")
+                .arg(
+                    Arg::with_name("DIRECTORY")
+                        .help("Directory containing dayNN.txt inputs [default: the cache]")
+                        .index(1),
+                )
+                .arg(preamble_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("fetch")
+                .about("Downloads a day's input into the cache")
+                .after_help(
+                    "The session cookie is read from AOC_SESSION. Inputs are cached in \
+                     AOC_CACHE_DIR if set, or else in aoc2020 under the user cache directory.",
+                )
+                .arg(day_arg(DAYS))
+                .arg(
+                    Arg::with_name("REFRESH")
+                        .long("refresh")
+                        .help("Download the input again even if it is cached"),
+                )
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("submit")
+                .about("Solves a part and submits the answer")
+                .after_help(
+                    "Every attempt is kept in history.jsonl in the cache directory. Answers \
+                     that were already wrong, or that a too high or too low hint rules out, \
+                     are not sent, and neither is anything while the site has asked for a wait.",
+                )
+                .arg(day_arg(DAYS))
+                .arg(
+                    Arg::with_name("PART")
+                        .help("Which part to submit")
+                        .required(true)
+                        .index(2)
+                        .possible_values(&["1", "2"]),
+                )
+                .arg(cached_input_arg(3))
+                .arg(preamble_arg())
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("report")
+                .about("Writes a page of every day's answers and timings")
+                .arg(
+                    Arg::with_name("DIRECTORY")
+                        .help("Directory containing dayNN.txt inputs [default: the cache]")
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("FORMAT")
+                        .long("format")
+                        .help("Page format")
+                        .takes_value(true)
+                        .possible_values(&["markdown", "html"])
+                        .default_value("markdown"),
+                )
+                .arg(
+                    Arg::with_name("OUTPUT")
+                        .short("o")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Write the page to FILE instead of standard output")
+                        .takes_value(true),
+                )
+                .arg(number_arg(
+                    "RUNS",
+                    "runs",
+                    "Time each day over N runs [default: 1]",
+                ))
+                .arg(preamble_arg())
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("plugins")
+                .about("Lists the day plugins that can be loaded")
+                .arg(plugins_arg())
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("serve")
+                .about("Answers solve requests over HTTP")
+                .after_help(
+                    "POST a puzzle input to /day/N to get its JSON report. The query string \
+                     may set part=1|2, runs=N and preamble=N.",
+                )
+                .arg(
+                    Arg::with_name("ADDRESS")
+                        .long("address")
+                        .value_name("HOST:PORT")
+                        .help("Address to listen on")
+                        .takes_value(true)
+                        .default_value("127.0.0.1:8020"),
+                )
+                .arg(number_arg(
+                    "TIMEOUT",
+                    "timeout",
+                    "Seconds to let a solve run before giving up [default: 10]",
+                ))
+                .arg(number_arg(
+                    "JOBS",
+                    "jobs",
+                    "How many solves may run at once [default: 4]",
+                ))
+                .arg(number_arg(
+                    "WORKERS",
+                    "workers",
+                    "How many requests may be handled at once [default: 8]",
+                ))
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("generate")
+                .about("Writes a random input for a day")
+                .arg(day_arg(DAYS))
+                .arg(number_arg("SEED", "seed", "Random seed [default: 0]"))
+                .arg(number_arg(
+                    "SIZE",
+                    "size",
+                    "Roughly how many lines to write [default: the size of a real input]",
+                ))
+                .arg(
+                    Arg::with_name("OUTPUT")
+                        .short("o")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Write the input to FILE and any planted answers to FILE.expected")
+                        .takes_value(true),
+                )
+                .arg(preamble_arg())
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("differential")
+                .about("Compares paired implementations on random inputs")
+                .arg(number_arg("SEED", "seed", "First random seed [default: 0]"))
+                .arg(number_arg(
+                    "RUNS",
+                    "runs",
+                    "How many seeds to try [default: 1000]",
+                ))
+                .arg(number_arg(
+                    "SIZE",
+                    "size",
+                    "The largest input size to try [default: 12]",
+                ))
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("fuzz")
+                .about("Feeds mutated inputs to a day's parser until one fails")
+                .arg(day_arg(DAYS))
+                .arg(number_arg("SEED", "seed", "Random seed [default: 0]"))
+                .arg(number_arg(
+                    "RUNS",
+                    "runs",
+                    "How many inputs to try [default: 10000]",
+                ))
+                .arg(number_arg(
+                    "TIMEOUT",
+                    "timeout",
+                    "Seconds to wait for each parse [default: 1]",
+                ))
+                .arg(
+                    Arg::with_name("CORPUS")
+                        .long("corpus")
+                        .value_name("DIR")
+                        .help("Save a failing input to DIR, such as aoc/tests/crashers")
+                        .takes_value(true),
+                )
+                .arg(aoc_common::quiet_arg())
+                .arg(aoc_common::verbose_arg()),
+        )
+        .subcommands(docs::subcommands());
+    let matches = docs::get_matches(app);
+
+    if let (_, Some(matches)) = matches.subcommand() {
+        aoc_common::set_verbosity(aoc_common::verbosity(matches));
+    }
+
+    aoc_common::exit(match matches.subcommand() {
+        ("day", Some(matches)) => day(matches),
+        ("all", Some(matches)) => all(matches),
+        ("fetch", Some(matches)) => fetch(matches),
+        ("submit", Some(matches)) => submit(matches),
+        ("report", Some(matches)) => report(matches),
+        ("dashboard", Some(matches)) => dashboard(matches),
+        ("plugins", Some(matches)) => list_plugins(matches),
+        ("serve", Some(matches)) => serve(matches),
+        ("generate", Some(matches)) => generate(matches),
+        ("differential", Some(matches)) => differential(matches),
+        ("fuzz", Some(matches)) => fuzz(matches),
+        _ => unreachable!(),
+    });
+}
@@ -0,0 +1,175 @@
+//! A page summarising every day's answers, for sharing progress.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc_common::{Report, Stats};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageFormat {
+    Markdown,
+    Html,
+}
+
+/// One day's row on the page.
+pub struct Entry {
+    pub day: u32,
+    pub input_lines: usize,
+    pub input_bytes: u64,
+    /// The report, or the error that stopped the day.
+    pub outcome: Result<Report, String>,
+}
+
+impl Entry {
+    /// The cells of the day's row in the summary table.
+    fn cells(&self) -> Vec<String> {
+        let input = format!("{} lines, {} bytes", self.input_lines, self.input_bytes);
+        match &self.outcome {
+            Ok(report) => vec![
+                self.day.to_string(),
+                report.answers.part1.clone().unwrap_or_default(),
+                report.answers.part2.clone().unwrap_or_default(),
+                median(Some(report.timings.parse)),
+                median(report.timings.part1),
+                median(report.timings.part2),
+                input,
+            ],
+            Err(error) => vec![
+                self.day.to_string(),
+                format!("error: {}", error),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                input,
+            ],
+        }
+    }
+
+    /// The drawings the day's answers made, by part.
+    fn visualisations(&self) -> Vec<(&str, &str)> {
+        let report = match &self.outcome {
+            Ok(report) => report,
+            Err(_) => return Vec::new(),
+        };
+
+        report
+            .visualisations
+            .iter()
+            .filter_map(|(part, drawing)| {
+                let title = match part.as_str() {
+                    "part1" => "Part 1",
+                    "part2" => "Part 2",
+                    _ => return None,
+                };
+                Some((title, drawing.as_str()?))
+            })
+            .collect()
+    }
+}
+
+const HEADINGS: [&str; 7] = [
+    "Day",
+    "Part 1",
+    "Part 2",
+    "Parse",
+    "Part 1 time",
+    "Part 2 time",
+    "Input",
+];
+
+fn median(stats: Option<Stats>) -> String {
+    stats.map_or_else(String::new, |stats| format_duration(stats.median))
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+/// Days in `1..=days` that have no entry.
+fn missing(entries: &[Entry], days: u32) -> Vec<String> {
+    (1..=days)
+        .filter(|day| !entries.iter().any(|entry| entry.day == *day))
+        .map(|day| day.to_string())
+        .collect()
+}
+
+pub fn render(format: PageFormat, entries: &[Entry], days: u32) -> String {
+    match format {
+        PageFormat::Markdown => markdown(entries, days),
+        PageFormat::Html => html(entries, days),
+    }
+}
+
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', " ")
+}
+
+pub fn markdown(entries: &[Entry], days: u32) -> String {
+    let mut page = String::from("# Advent of Code 2020\n\n");
+
+    writeln!(page, "| {} |", HEADINGS.join(" | ")).unwrap();
+    writeln!(page, "|---:|---|---|---:|---:|---:|---:|").unwrap();
+    for entry in entries {
+        let cells: Vec<String> = entry.cells().iter().map(|c| markdown_cell(c)).collect();
+        writeln!(page, "| {} |", cells.join(" | ")).unwrap();
+    }
+
+    let missing = missing(entries, days);
+    if !missing.is_empty() {
+        writeln!(page, "\nNo input for days {}.", missing.join(", ")).unwrap();
+    }
+
+    for entry in entries {
+        for (title, drawing) in entry.visualisations() {
+            writeln!(page, "\n## Day {}, {}\n", entry.day, title).unwrap();
+            writeln!(page, "```text\n{}\n```", drawing.trim_end()).unwrap();
+        }
+    }
+
+    page
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn html(entries: &[Entry], days: u32) -> String {
+    let mut page = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code 2020</title>\n</head>\n<body>\n\
+         <h1>Advent of Code 2020</h1>\n<table>\n<tr>",
+    );
+
+    for heading in &HEADINGS {
+        write!(page, "<th>{}</th>", heading).unwrap();
+    }
+    page.push_str("</tr>\n");
+
+    for entry in entries {
+        page.push_str("<tr>");
+        for cell in entry.cells() {
+            write!(page, "<td>{}</td>", escape(&cell)).unwrap();
+        }
+        page.push_str("</tr>\n");
+    }
+    page.push_str("</table>\n");
+
+    let missing = missing(entries, days);
+    if !missing.is_empty() {
+        writeln!(page, "<p>No input for days {}.</p>", missing.join(", ")).unwrap();
+    }
+
+    for entry in entries {
+        for (title, drawing) in entry.visualisations() {
+            writeln!(page, "<h2>Day {}, {}</h2>", entry.day, title).unwrap();
+            writeln!(page, "<pre>{}</pre>", escape(drawing.trim_end())).unwrap();
+        }
+    }
+
+    page.push_str("</body>\n</html>\n");
+    page
+}
@@ -0,0 +1,222 @@
+//! Day solvers loaded at runtime from shared libraries in a directory.
+//!
+//! See [`aoc_common::plugin`] for the interface a plugin provides.
+
+use std::env;
+use std::ffi::CStr;
+use std::fs;
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+
+use aoc_common::plugin::{self, Position, RegisterFn, SolveFn, ABI_VERSION, PART_ONE, PART_TWO};
+use aoc_common::{info, Error, Part, Result, Solution};
+use libloading::Library;
+
+/// The last day of the event, which plugins may add solvers up to.
+pub const LAST_DAY: u32 = 25;
+
+/// The environment variable naming the plugin directory.
+pub const PLUGIN_VAR: &str = "AOC_PLUGIN_DIR";
+
+/// The buffer first offered for an answer. It is grown if a plugin says
+/// the answer needs more.
+const ANSWER_LEN: usize = 4096;
+
+/// The most an answer may take up.
+const MAX_ANSWER_LEN: usize = 1 << 20;
+
+/// The plugin directory named by `AOC_PLUGIN_DIR`, if it is set.
+pub fn directory() -> Option<PathBuf> {
+    env::var_os(PLUGIN_VAR)
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from)
+}
+
+/// A day solver from a shared library.
+pub struct Plugin {
+    pub path: PathBuf,
+    pub day: u32,
+    pub name: String,
+    /// `PART_ONE`, `PART_TWO` or both.
+    pub parts: u32,
+    pub input_format: String,
+    solve: SolveFn,
+    // Dropped last, as `solve` points into it.
+    _library: Library,
+}
+
+/// Reads a NUL-terminated string from a registration.
+///
+/// # Safety
+///
+/// `text` must be null or point to a NUL-terminated string.
+unsafe fn string(text: *const c_char) -> String {
+    if text.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(text).to_string_lossy().into_owned()
+    }
+}
+
+impl Plugin {
+    /// Loads the plugin at `path` and checks that it speaks this version of
+    /// the interface.
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = path.to_string_lossy();
+        let refuse = |cause: String| Error::config(cause).file(&file);
+
+        // Loading runs the library's initialisers, so only plugins that are
+        // trusted belong in the directory.
+        let library =
+            unsafe { Library::new(path) }.map_err(|e| refuse(format!("cannot load: {}", e)))?;
+
+        let registration = unsafe {
+            let register = library
+                .get::<RegisterFn>(plugin::ENTRY_POINT.as_bytes())
+                .map_err(|_| refuse(format!("no {} entry point", plugin::ENTRY_POINT)))?;
+            register(ABI_VERSION).as_ref()
+        };
+        let registration = registration.ok_or_else(|| {
+            refuse(format!(
+                "does not support plugin ABI version {}",
+                ABI_VERSION
+            ))
+        })?;
+
+        if registration.abi_version != ABI_VERSION {
+            return Err(refuse(format!(
+                "built for plugin ABI version {}, not {}",
+                registration.abi_version, ABI_VERSION
+            )));
+        }
+        if !(1..=LAST_DAY).contains(&registration.day) {
+            return Err(refuse(format!(
+                "registers day {}, which is not between 1 and {}",
+                registration.day, LAST_DAY
+            )));
+        }
+        if registration.parts & (PART_ONE | PART_TWO) == 0 {
+            return Err(refuse(String::from("solves neither part")));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            day: registration.day,
+            name: unsafe { string(registration.name) },
+            parts: registration.parts,
+            input_format: unsafe { string(registration.input_format) },
+            solve: registration.solve,
+            _library: library,
+        })
+    }
+
+    /// Whether the plugin solves part `part`.
+    pub fn solves(&self, part: u32) -> bool {
+        match part {
+            1 => self.parts & PART_ONE != 0,
+            2 => self.parts & PART_TWO != 0,
+            _ => false,
+        }
+    }
+
+    /// The parts to run when `requested` were asked for.
+    pub fn part(&self, requested: Part) -> Part {
+        plugin::parts(self.parts, requested)
+    }
+
+    fn call(&self, part: u32, lines: &[String]) -> Result<String> {
+        if !self.solves(part) {
+            return Err(Error::config(format!(
+                "plugin {} does not solve part {}",
+                self.name, part
+            )));
+        }
+
+        let mut input = lines.join("\n");
+        input.push('\n');
+        let mut out = vec![0u8; ANSWER_LEN];
+
+        loop {
+            let mut position = Position::default();
+            let code = unsafe {
+                (self.solve)(
+                    part,
+                    input.as_ptr(),
+                    input.len(),
+                    out.as_mut_ptr() as *mut c_char,
+                    out.len(),
+                    &mut position,
+                )
+            };
+
+            let end = out.iter().position(|b| *b == 0).unwrap_or(out.len());
+            let text = String::from_utf8_lossy(&out[..end]).into_owned();
+
+            match code {
+                plugin::OK => return Ok(text),
+                plugin::ERR_BUFFER if out.len() < MAX_ANSWER_LEN => {
+                    out.resize(out.len() * 2, 0);
+                }
+                code => return Err(plugin::error(code, &text, position)),
+            }
+        }
+    }
+}
+
+/// A plugin is run as a solution whose parsing happens in each part, as
+/// the interface has no way to keep a parsed input between calls.
+impl Solution for Plugin {
+    type Input = Vec<String>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<Self::Part1> {
+        self.call(1, lines)
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<Self::Part2> {
+        self.call(2, lines)
+    }
+}
+
+/// Loads every shared library in `directory`, ordered by day and then
+/// name. A missing directory has no plugins.
+///
+/// A library that cannot be loaded as a plugin is skipped with a note, so
+/// that one stray file does not stop the days that do not need it. Asking
+/// for it by name then fails, as no plugin has that name.
+pub fn discover(directory: &Path) -> Result<Vec<Plugin>> {
+    let name = directory.to_string_lossy();
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::from(e).file(&name)),
+    };
+
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| Error::from(e).file(&name))?.path();
+        if path.extension() == Some(env::consts::DLL_EXTENSION.as_ref()) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut plugins: Vec<Plugin> = paths
+        .iter()
+        .filter_map(|path| match Plugin::load(path) {
+            Ok(plugin) => Some(plugin),
+            Err(error) => {
+                info!("skipping plugin {}", error);
+                None
+            }
+        })
+        .collect();
+    plugins.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+
+    Ok(plugins)
+}
@@ -0,0 +1,303 @@
+//! A local HTTP API over every day's solver.
+//!
+//! `POST /day/N` with the puzzle input as the body answers with the same
+//! JSON report as `aoc day N --format json`. The query string may hold
+//! `part`, `runs` and, for day 9, `preamble`. `GET /` describes the API.
+//!
+//! Requests are handled by a fixed number of workers, so a flood of slow
+//! uploads cannot tie up more threads or memory than that. Each solve runs
+//! on a thread of its own and is abandoned if it outlasts the timeout. An
+//! abandoned thread cannot be stopped and keeps its slot until it
+//! finishes, so the number of solves running at once is capped too.
+
+use std::io::Read;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{normalise, Error, ErrorKind, Part, Result};
+use tiny_http::{Header, Method, Response};
+
+use crate::{Options, DAYS};
+
+/// The largest input accepted, well above any real puzzle input.
+const MAX_INPUT: usize = 4 << 20;
+
+/// The most runs a request may ask to time.
+const MAX_RUNS: usize = 100;
+
+/// The stack each solve runs on, set rather than left to the platform so
+/// that the depth the solvers allow for fits on every one. Running out of
+/// stack aborts the whole process rather than just the solve.
+const SOLVE_STACK: usize = 16 << 20;
+
+pub struct Config {
+    /// How long a solve may take before the request gives up on it.
+    pub timeout: Duration,
+    /// How many solves may run at once, counting abandoned ones.
+    pub max_running: usize,
+    /// How many requests may be read and answered at once.
+    pub workers: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            max_running: 4,
+            workers: 8,
+        }
+    }
+}
+
+pub struct Server {
+    http: Arc<tiny_http::Server>,
+    config: Arc<Config>,
+    running: Arc<AtomicUsize>,
+}
+
+impl Server {
+    pub fn bind(address: &str, config: Config) -> Result<Self> {
+        let http = tiny_http::Server::http(address)
+            .map_err(|e| Error::config(format!("cannot listen on {}: {}", address, e)))?;
+
+        Ok(Self {
+            http: Arc::new(http),
+            config: Arc::new(config),
+            running: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// The address the server is listening on, which tells the port chosen
+    /// when binding to port 0.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.http.server_addr().to_ip()
+    }
+
+    /// Answers requests on the configured number of workers, for as long
+    /// as the process lives.
+    pub fn run(self) {
+        let workers: Vec<_> = (0..self.config.workers.max(1))
+            .map(|_| {
+                let http = Arc::clone(&self.http);
+                let config = Arc::clone(&self.config);
+                let running = Arc::clone(&self.running);
+                thread::spawn(move || {
+                    for request in http.incoming_requests() {
+                        handle(request, &config, &running);
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn handle(mut request: tiny_http::Request, config: &Config, running: &Arc<AtomicUsize>) {
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT as u64 + 1)
+        .read_to_end(&mut body);
+
+    let (status, value) = match read {
+        Err(e) => (400, error_json("bad_request", &e.to_string())),
+        Ok(_) if body.len() > MAX_INPUT => {
+            (413, error_json("bad_request", "the input is too large"))
+        }
+        Ok(_) => route(request.method(), request.url(), &body, config, running),
+    };
+
+    let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(value.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+fn error_json(kind: &str, cause: &str) -> Value {
+    json!({ "error": { "kind": kind, "cause": cause } })
+}
+
+fn kind_name(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::BadInput => "bad_input",
+        ErrorKind::NoSolution => "no_solution",
+        ErrorKind::Io => "io",
+        ErrorKind::Remote => "remote",
+        ErrorKind::Config => "config",
+        ErrorKind::Refused => "refused",
+        ErrorKind::Internal => "internal",
+        ErrorKind::Failed => "failed",
+    }
+}
+
+/// The status and JSON for an error from a solver.
+fn solver_error(error: &Error) -> (u16, Value) {
+    let status = match error.kind {
+        ErrorKind::BadInput => 400,
+        ErrorKind::NoSolution => 422,
+        _ => 500,
+    };
+
+    (
+        status,
+        json!({
+            "error": {
+                "kind": kind_name(error.kind),
+                "cause": error.cause,
+                "line": error.line,
+                "column": error.column,
+            }
+        }),
+    )
+}
+
+/// The parameters of a solve, read from a query string.
+struct Query {
+    part: Part,
+    runs: usize,
+    options: Options,
+}
+
+fn parse_query(query: &str) -> std::result::Result<Query, String> {
+    let mut parsed = Query {
+        part: Part::Both,
+        runs: 1,
+        options: Options::default(),
+    };
+
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let number = || usize::from_str(value).map_err(|_| format!("{} must be a number", key));
+
+        match key {
+            "part" => {
+                parsed.part = match value {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return Err(String::from("part must be 1 or 2")),
+                }
+            }
+            "runs" => match number()? {
+                runs @ 1..=MAX_RUNS => parsed.runs = runs,
+                _ => return Err(format!("runs must be between 1 and {}", MAX_RUNS)),
+            },
+            "preamble" => parsed.options.preamble = number()?,
+            _ => return Err(format!("unknown parameter {:?}", key)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Works out the response to a request.
+fn route(
+    method: &Method,
+    url: &str,
+    body: &[u8],
+    config: &Config,
+    running: &Arc<AtomicUsize>,
+) -> (u16, Value) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+
+    if path == "/" {
+        return match method {
+            Method::Get => (
+                200,
+                json!({
+                    "days": DAYS,
+                    "usage": "POST the puzzle input to /day/N, optionally with ?part=1|2, runs=N or preamble=N",
+                }),
+            ),
+            _ => (405, error_json("bad_request", "use GET for /")),
+        };
+    }
+
+    let day = match path.strip_prefix("/day/").map(u32::from_str) {
+        Some(Ok(day)) if (1..=DAYS).contains(&day) => day,
+        _ => {
+            return (
+                404,
+                error_json("bad_request", &format!("no such path: {}", path)),
+            )
+        }
+    };
+    if *method != Method::Post {
+        return (
+            405,
+            error_json("bad_request", "use POST with the puzzle input as the body"),
+        );
+    }
+
+    let query = match parse_query(query) {
+        Ok(query) => query,
+        Err(cause) => return (400, error_json("bad_request", &cause)),
+    };
+    let input = match std::str::from_utf8(body) {
+        Ok(input) => input,
+        Err(_) => return (400, error_json("bad_input", "the input is not UTF-8")),
+    };
+
+    solve(day, normalise(input).0, query, config, running)
+}
+
+/// Releases a slot in the running count when a solve ends, however it
+/// ends.
+struct Slot(Arc<AtomicUsize>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn solve(
+    day: u32,
+    lines: Vec<String>,
+    query: Query,
+    config: &Config,
+    running: &Arc<AtomicUsize>,
+) -> (u16, Value) {
+    if running.fetch_add(1, Ordering::SeqCst) >= config.max_running {
+        running.fetch_sub(1, Ordering::SeqCst);
+        return (
+            503,
+            error_json("busy", "too many solves are running; try again later"),
+        );
+    }
+    let slot = Slot(Arc::clone(running));
+
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .stack_size(SOLVE_STACK)
+        .spawn(move || {
+            let _slot = slot;
+            let report = crate::solver(day, &query.options)
+                .report(day, &lines, query.part, query.runs)
+                .map(|report| report.to_json());
+            let _ = sender.send(report);
+        });
+    if let Err(error) = spawned {
+        return (500, error_json("internal", &error.to_string()));
+    }
+
+    match receiver.recv_timeout(config.timeout) {
+        Ok(Ok(report)) => (200, report),
+        Ok(Err(error)) => solver_error(&error),
+        Err(mpsc::RecvTimeoutError::Timeout) => (
+            504,
+            error_json("timeout", &format!("gave up after {:.1?}", config.timeout)),
+        ),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            (500, error_json("panic", "the solver panicked"))
+        }
+    }
+}
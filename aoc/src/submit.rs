@@ -0,0 +1,322 @@
+//! Submitting answers, with a local history of every attempt.
+//!
+//! The history is kept so that an answer the site has already turned down
+//! is never sent again, and so that the wait the site asks for after a
+//! wrong answer is respected before it is asked to check another, for any
+//! day.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::serde_json::{self, json, Value};
+use aoc_common::{Error, Result};
+
+use crate::client::Client;
+
+/// How long to wait after a wrong answer when the site does not say.
+const DEFAULT_WAIT: u64 = 60;
+
+/// What the site made of an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The hint is "too high" or "too low", when the site gives one.
+    Wrong {
+        hint: Option<String>,
+    },
+    /// The answer was not checked because the last one was too recent.
+    TooSoon,
+    /// The part has already been solved, or is not unlocked yet.
+    WrongLevel,
+    /// A reply that was not understood.
+    Unknown,
+}
+
+impl Outcome {
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong { .. } => "wrong",
+            Outcome::TooSoon => "too soon",
+            Outcome::WrongLevel => "wrong level",
+            Outcome::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Wrong { hint: Some(hint) } => write!(f, "wrong, {}", hint),
+            outcome => write!(f, "{}", outcome.name()),
+        }
+    }
+}
+
+/// One submission and the site's reply to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub outcome: Outcome,
+    /// When the site will next check an answer, for any day, if it asked
+    /// for a wait.
+    pub wait_until: Option<u64>,
+    pub message: String,
+}
+
+impl Attempt {
+    /// Reads what the site said in `message`.
+    pub fn from_reply(day: u32, part: u32, answer: &str, time: u64, message: &str) -> Self {
+        let outcome = if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .iter()
+                .find(|hint| message.contains(&format!("your answer is {}", hint)))
+                .map(|hint| hint.to_string());
+            Outcome::Wrong { hint }
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown
+        };
+
+        let wait = match outcome {
+            Outcome::Wrong { .. } => Some(wait_seconds(message).unwrap_or(DEFAULT_WAIT)),
+            Outcome::TooSoon => Some(wait_seconds(message).unwrap_or(DEFAULT_WAIT)),
+            _ => None,
+        };
+
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            time,
+            outcome,
+            wait_until: wait.map(|wait| time.saturating_add(wait)),
+            message: message.to_string(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer,
+            "time": self.time,
+            "outcome": self.outcome.name(),
+            "hint": match &self.outcome {
+                Outcome::Wrong { hint } => hint.clone(),
+                _ => None,
+            },
+            "wait_until": self.wait_until,
+            "message": self.message,
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let hint = value["hint"].as_str().map(String::from);
+        let outcome = match value["outcome"].as_str()? {
+            "correct" => Outcome::Correct,
+            "wrong" => Outcome::Wrong { hint },
+            "too soon" => Outcome::TooSoon,
+            "wrong level" => Outcome::WrongLevel,
+            _ => Outcome::Unknown,
+        };
+
+        Some(Self {
+            day: value["day"].as_u64()? as u32,
+            part: value["part"].as_u64()? as u32,
+            answer: value["answer"].as_str()?.to_string(),
+            time: value["time"].as_u64()?,
+            outcome,
+            wait_until: value["wait_until"].as_u64(),
+            message: value["message"].as_str().unwrap_or("").to_string(),
+        })
+    }
+}
+
+/// Finds how long the site asked to wait, from either "please wait one
+/// minute before trying again" or "You have 1m 5s left to wait".
+fn wait_seconds(message: &str) -> Option<u64> {
+    if let Some((before, _)) = message.split_once(" left to wait") {
+        let amount = before.rsplit("You have ").next()?;
+        return amount.split_whitespace().try_fold(0u64, |total, part| {
+            let (at, unit) = part.char_indices().last()?;
+            let number = u64::from_str(&part[..at]).ok()?;
+            let seconds = match unit {
+                'h' => number.checked_mul(3600)?,
+                'm' => number.checked_mul(60)?,
+                's' => number,
+                _ => return None,
+            };
+            total.checked_add(seconds)
+        });
+    }
+
+    let (_, after) = message.split_once("wait ")?;
+    let mut words = after.split_whitespace();
+    let number = words.next()?;
+    let number = u64::from_str(number).ok().or_else(|| {
+        [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        ]
+        .iter()
+        .position(|word| word.eq_ignore_ascii_case(number))
+        .map(|n| n as u64 + 1)
+    })?;
+
+    match words.next()? {
+        unit if unit.starts_with("minute") => number.checked_mul(60),
+        unit if unit.starts_with("second") => Some(number),
+        _ => None,
+    }
+}
+
+/// Every attempt made so far, stored as one JSON object per line.
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// The history file's name within the cache directory.
+    pub const FILE_NAME: &'static str = "history.jsonl";
+
+    /// Loads the history at `path`, which is empty if the file does not
+    /// exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::from(e).file(&path.to_string_lossy())),
+        };
+
+        let attempts = aoc_common::parse_lines(
+            &text.lines().map(String::from).collect::<Vec<_>>(),
+            |line| {
+                serde_json::from_str(line)
+                    .ok()
+                    .as_ref()
+                    .and_then(Attempt::from_json)
+                    .ok_or_else(|| Error::bad_input("not a submission record"))
+            },
+        )
+        .map_err(|e| e.file(&path.to_string_lossy()))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Checks that submitting `answer` now could do some good.
+    pub fn check(&self, day: u32, part: u32, answer: &str, now: u64) -> Result<()> {
+        let same_part = || {
+            self.attempts
+                .iter()
+                .filter(move |a| a.day == day && a.part == part)
+        };
+
+        if let Some(solved) = same_part().find(|a| a.outcome == Outcome::Correct) {
+            return Err(Error::refused(format!(
+                "day {} part {} is already solved with {}",
+                day, part, solved.answer
+            )));
+        }
+
+        for attempt in same_part() {
+            if let Outcome::Wrong { hint } = &attempt.outcome {
+                if attempt.answer == answer {
+                    return Err(Error::refused(format!("{} was already wrong", answer)));
+                }
+
+                let beyond = match (i128::from_str(answer), i128::from_str(&attempt.answer)) {
+                    (Ok(new), Ok(old)) => match hint.as_deref() {
+                        Some("too high") => new >= old,
+                        Some("too low") => new <= old,
+                        _ => false,
+                    },
+                    _ => false,
+                };
+                if beyond {
+                    return Err(Error::refused(format!(
+                        "{} cannot be right, as {} was {}",
+                        answer,
+                        attempt.answer,
+                        hint.as_deref().unwrap_or("")
+                    )));
+                }
+            }
+        }
+
+        // The site limits how often an account answers, whatever the day.
+        let wait_until = self.attempts.iter().filter_map(|a| a.wait_until).max();
+        if let Some(until) = wait_until.filter(|until| *until > now) {
+            return Err(Error::refused(format!(
+                "the site asked for a wait; try again in {}s",
+                until - now
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Adds `attempt` to the history and appends it to the file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let path = self.path.to_string_lossy().into_owned();
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory).map_err(|e| Error::from(e).file(&path))?;
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{}", attempt.to_json()))
+            .map_err(|e| Error::from(e).file(&path))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Submits `answer` unless the history shows it would be wasted, and
+/// records what the site said.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u32,
+    part: u32,
+    answer: &str,
+    now: u64,
+) -> Result<Attempt> {
+    history.check(day, part, answer, now)?;
+
+    let message = client.answer(day, part, answer)?;
+    let attempt = Attempt::from_reply(day, part, answer, now, &message);
+    history.record(attempt.clone())?;
+
+    Ok(attempt)
+}
@@ -0,0 +1,41 @@
+//! Scratch directories for tests that need files of their own.
+
+use std::env;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// An empty directory for one test, removed with everything in it when
+/// dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Creates a directory named for the test binary, this run and `name`,
+    /// which must differ between the tests in a binary.
+    pub fn new(name: &str) -> Self {
+        let directory = env::temp_dir().join(format!(
+            "aoc-{}-{}-{}",
+            env!("CARGO_CRATE_NAME"),
+            process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        Self(directory)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
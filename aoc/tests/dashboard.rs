@@ -0,0 +1,163 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::dashboard::Dashboard;
+use aoc::Options;
+use aoc_common::Part;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::Terminal;
+
+mod common;
+
+use common::TempDir;
+
+/// An input directory of its own for each test, holding day 1's example.
+fn input_directory(name: &str) -> TempDir {
+    let directory = TempDir::new(name);
+    fs::copy(
+        workspace().join("day01/test1.txt"),
+        directory.join("day01.txt"),
+    )
+    .unwrap();
+    directory
+}
+
+fn workspace() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn lines(path: &str) -> Vec<String> {
+    aoc_common::read_lines_from(&workspace().join(path).to_string_lossy()).unwrap()
+}
+
+/// Draws `dashboard` and returns the screen as text.
+fn screen(dashboard: &Dashboard) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    terminal.draw(|screen| dashboard.draw(screen)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn wait(dashboard: &mut Dashboard) {
+    let start = Instant::now();
+    while dashboard.is_running() {
+        assert!(
+            start.elapsed() < Duration::from_secs(30),
+            "the run never finished"
+        );
+        thread::sleep(Duration::from_millis(10));
+        dashboard.poll();
+    }
+}
+
+fn press(dashboard: &mut Dashboard, keys: &[KeyCode]) {
+    for key in keys {
+        assert!(!dashboard.key(*key));
+    }
+}
+
+fn type_text(dashboard: &mut Dashboard, text: &str) {
+    for c in text.chars() {
+        dashboard.key(KeyCode::Char(c));
+    }
+}
+
+#[test]
+fn days_run_and_show_their_answers() {
+    let directory = input_directory("run");
+    let mut dashboard = Dashboard::new(&directory, Options::default());
+
+    let before = screen(&dashboard);
+    assert!(before.contains("ready"));
+    assert!(before.contains("no input"));
+
+    press(&mut dashboard, &[KeyCode::Enter]);
+    wait(&mut dashboard);
+
+    let after = screen(&dashboard);
+    assert!(after.contains("solved"), "{}", after);
+    assert!(after.contains("514579"));
+    assert!(after.contains("241861950"));
+    assert_eq!(dashboard.message(), "Day 1 finished");
+}
+
+#[test]
+fn days_without_input_ask_for_one() {
+    let directory = input_directory("missing");
+    let mut dashboard = Dashboard::new(&directory, Options::default());
+
+    press(&mut dashboard, &[KeyCode::Down, KeyCode::Enter]);
+    assert_eq!(dashboard.selected(), 2);
+    assert!(!dashboard.is_running());
+    assert_eq!(
+        dashboard.message(),
+        "Day 2 has no input; press i to choose one"
+    );
+
+    press(&mut dashboard, &[KeyCode::Char('i')]);
+    type_text(&mut dashboard, "nowhere.txt");
+    press(&mut dashboard, &[KeyCode::Enter, KeyCode::Enter]);
+    wait(&mut dashboard);
+
+    assert!(dashboard.message().starts_with("Day 2: nowhere.txt: "));
+    assert!(screen(&dashboard).contains("error"));
+}
+
+#[test]
+fn visual_days_step_through_frames() {
+    let directory = input_directory("frames");
+    let mut dashboard = Dashboard::new(&directory, Options::default());
+    let input = workspace().join("day11/test1.txt");
+
+    press(&mut dashboard, &[KeyCode::Down; 10]);
+    assert_eq!(dashboard.selected(), 11);
+    press(&mut dashboard, &[KeyCode::Char('i')]);
+    type_text(&mut dashboard, &input.to_string_lossy());
+    press(&mut dashboard, &[KeyCode::Enter, KeyCode::Char('f')]);
+    wait(&mut dashboard);
+
+    let frames = aoc::frames(11, &lines("day11/test1.txt"), Part::One)
+        .unwrap()
+        .unwrap();
+    assert_eq!(dashboard.frame(), Some(&frames[0]));
+    assert!(screen(&dashboard).contains("Day 11, part 1: Start (1 of"));
+
+    press(&mut dashboard, &[KeyCode::Right]);
+    assert_eq!(dashboard.frame().unwrap().title, "Round 1");
+
+    press(&mut dashboard, &[KeyCode::End, KeyCode::Right]);
+    assert_eq!(dashboard.frame(), frames.last());
+
+    press(&mut dashboard, &[KeyCode::Esc]);
+    assert_eq!(dashboard.frame(), None);
+    assert!(dashboard.key(KeyCode::Char('q')));
+}
+
+#[test]
+fn frames_end_on_the_drawn_answer() {
+    let lines = lines("day17/test1.txt");
+    let frames = aoc::frames(17, &lines, Part::One).unwrap().unwrap();
+    let report = aoc::solver(17, &Options::default())
+        .report(17, &lines, Part::One, 1)
+        .unwrap();
+
+    assert_eq!(frames.len(), 7);
+    assert_eq!(frames[0].title, "Start");
+    assert_eq!(frames[6].title, "Cycle 6");
+    assert_eq!(
+        Some(frames[6].picture.as_str()),
+        report.visualisations["part1"].as_str()
+    );
+
+    assert!(aoc::frames(17, &lines, Part::Two).is_none());
+    assert!(aoc::frames(1, &lines, Part::One).is_none());
+}
@@ -0,0 +1,53 @@
+use std::process::Command;
+
+use aoc_common::differential::Differential;
+use aoc_common::Rng;
+
+/// Enough seeds to cover every size a few times over.
+const SEEDS: u64 = 300;
+
+/// Small enough that the slow references stay quick.
+const MAX_SIZE: usize = 12;
+
+#[test]
+fn paired_implementations_agree() {
+    let disagreements: Vec<String> = aoc::differentials()
+        .iter()
+        .filter_map(|check| check.check(0..SEEDS, MAX_SIZE))
+        .map(|disagreement| disagreement.to_string())
+        .collect();
+
+    assert!(disagreements.is_empty(), "{}", disagreements.join("\n"));
+}
+
+#[test]
+fn the_seeds_stop_at_the_largest() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "differential",
+            "--seed",
+            "18446744073709551610",
+            "--runs",
+            "10",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn sizes_beyond_the_largest_are_capped() {
+    let (numbers, turns) =
+        day15::differential::SearchVsMemory.generate(&mut Rng::new(0), usize::MAX);
+    assert!(
+        numbers.len() <= 1001 && turns <= 20_001,
+        "{:?} {}",
+        numbers,
+        turns
+    );
+}
@@ -0,0 +1,45 @@
+use std::process::Command;
+
+fn aoc(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "aoc {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn completions_cover_every_subcommand() {
+    for shell in &["bash", "zsh", "fish"] {
+        let script = aoc(&["completions", shell]);
+
+        for subcommand in &["day", "all", "fetch", "submit", "completions", "man"] {
+            assert!(script.contains(subcommand), "{}: no {}", shell, subcommand);
+        }
+        assert!(script.contains("preamble"), "{}: no --preamble", shell);
+    }
+}
+
+#[test]
+fn unknown_shells_are_refused() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["completions", "tcsh"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[possible values: bash, fish, zsh]"));
+}
+
+#[test]
+fn the_man_page_has_a_section_per_subcommand() {
+    let page = aoc(&["man"]);
+
+    assert!(page.starts_with(".TH \"AOC\" \"1\""));
+    assert!(page.contains(".SH NAME\naoc \\- AOC2020\n"));
+    assert!(page.contains(".SS \"aoc day\"\n.nf\n"));
+    assert!(page.contains("aoc day [FLAGS] [OPTIONS] <DAY> [INPUT]"));
+    assert!(page.contains("--preamble <PREAMBLE>"));
+    assert!(page.lines().all(|line| line == line.trim_end()));
+}
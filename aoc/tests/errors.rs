@@ -0,0 +1,302 @@
+use std::fs;
+use std::process::Command;
+
+use aoc::Options;
+use aoc_common::{Error, ErrorKind, Part};
+
+mod common;
+
+use common::TempDir;
+
+fn solve(day: u32, input: &str, part: Part) -> Error {
+    aoc_common::set_verbosity(aoc_common::Verbosity::Quiet);
+
+    let lines: Vec<String> = input.lines().map(String::from).collect();
+    aoc::solver(day, &Options::default())
+        .solve(&lines, part)
+        .expect_err("expected an error")
+}
+
+fn location(error: &Error) -> (Option<usize>, Option<usize>) {
+    (error.line, error.column)
+}
+
+#[test]
+fn bad_numbers_report_line_and_column() {
+    let error = solve(1, "1721\n979\n36x\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+    assert_eq!(location(&error), (Some(3), Some(1)));
+
+    let error = solve(2, "1-3 a: abcde\n1-x b: cdefg\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+    assert_eq!(location(&error), (Some(2), Some(3)));
+}
+
+#[test]
+fn unknown_tokens_are_bad_input() {
+    let error = solve(8, "nop +0\nhcf +1\n", Part::One);
+    assert_eq!(location(&error), (Some(2), Some(1)));
+
+    let error = solve(11, "L.L\nL#L\n", Part::One);
+    assert_eq!(location(&error), (Some(2), Some(2)));
+
+    let error = solve(12, "F10\nQ3\n", Part::One);
+    assert_eq!(location(&error), (Some(2), Some(1)));
+
+    let error = solve(12, "R45\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+
+    let error = solve(
+        14,
+        "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0Z\n",
+        Part::One,
+    );
+    assert_eq!(error.kind, ErrorKind::BadInput);
+
+    let error = solve(18, "1 + (2 * 3\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+    assert_eq!(error.line, Some(1));
+
+    let error = solve(18, "1 + + 2\n", Part::One);
+    assert_eq!(location(&error), (Some(1), Some(5)));
+}
+
+#[test]
+fn missing_fields_are_bad_input() {
+    let error = solve(4, "ecl:gry pid\n", Part::One);
+    assert_eq!(location(&error), (Some(1), Some(9)));
+
+    let error = solve(7, "light red bags contain 1 bright white bag\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+
+    let error = solve(13, "939\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+}
+
+#[test]
+fn unsolvable_inputs_are_reported() {
+    let error = solve(1, "1\n2\n3\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let error = solve(8, "nop +0\njmp +1\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let error = solve(10, "1\n5\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+}
+
+#[test]
+fn exit_codes_are_distinct() {
+    let codes = [
+        ErrorKind::BadInput.exit_code(),
+        ErrorKind::Io.exit_code(),
+        ErrorKind::NoSolution.exit_code(),
+        ErrorKind::Remote.exit_code(),
+        ErrorKind::Config.exit_code(),
+        ErrorKind::Refused.exit_code(),
+        ErrorKind::Internal.exit_code(),
+        ErrorKind::Failed.exit_code(),
+    ];
+
+    for (i, code) in codes.iter().enumerate() {
+        assert_ne!(*code, 0);
+        assert_ne!(*code, 1);
+        assert!(!codes[i + 1..].contains(code));
+    }
+}
+
+#[test]
+fn errors_display_their_location() {
+    let error = Error::bad_input("unexpected 'Q'")
+        .column(4)
+        .line(2)
+        .file("input.txt");
+    assert_eq!(error.to_string(), "input.txt:2:4: unexpected 'Q'");
+
+    let error = aoc_common::read_lines_from("/nonexistent/input.txt").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Io);
+    assert_eq!(error.file.as_deref(), Some("/nonexistent/input.txt"));
+}
+
+#[test]
+fn repeated_passport_fields_report_the_record() {
+    let error = solve(4, "\nbyr:1937\n\necl:gry\niyr:2017 ecl:amb\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+    assert_eq!(location(&error), (Some(5), Some(10)));
+    assert!(error.cause.contains("lines 4 to 5"), "{}", error);
+}
+
+#[test]
+fn values_too_large_to_work_with_are_errors() {
+    let lines = |input: &str| -> Vec<String> { input.lines().map(String::from).collect() };
+    let solve_both = |day: u32, input: &str| {
+        aoc::solver(day, &Options::default()).solve(&lines(input), Part::Both)
+    };
+
+    let error = solve(1, "4294967295\n1\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let answers = solve_both(12, &"F2147483647\n".repeat(5)).unwrap();
+    assert_eq!(answers.part1.as_deref(), Some("10737418235"));
+    let error = solve(12, &"F9223372036854775807\n".repeat(2), Part::One);
+    assert_eq!(location(&error), (Some(2), None));
+    let error = solve(12, "F9223372036854775807\n", Part::Two);
+    assert_eq!(location(&error), (Some(1), None));
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let error = solve(3, &"#\n".repeat(10_000), Part::Two);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let error = solve(13, "1\n4294967311\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let error = solve(
+        7,
+        "shiny gold bags contain 4000000000 dark red bags, 4000000000 dark blue bags.\n",
+        Part::Two,
+    );
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let chain: String = (1..=200).map(|n| format!("{}\n", n)).collect();
+    let error = solve(10, &chain, Part::Two);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+    let chain: String = (1..=60).map(|n| format!("{}\n", n)).collect();
+    let answers = aoc::solver(10, &Options::default())
+        .solve(&lines(&chain), Part::Two)
+        .unwrap();
+    assert_eq!(answers.part2.as_deref(), Some("4680045560037375"));
+
+    let answers = solve_both(8, "nop +0\njmp +2147483647\n");
+    assert_eq!(answers.unwrap_err().kind, ErrorKind::NoSolution);
+    let answers = aoc::solver(8, &Options::default())
+        .solve(&lines("nop +0\njmp +2147483647\n"), Part::Two)
+        .unwrap();
+    assert_eq!(answers.part2.as_deref(), Some("0"));
+    let error = solve(8, "acc +2147483647\nacc +1\n", Part::One);
+    assert_eq!(location(&error), (Some(2), None));
+    let program = "jmp +3\nacc +2000000000\nacc +2000000000\njmp +0\n";
+    let answers = aoc::solver(8, &Options::default())
+        .solve(&lines(program), Part::Two)
+        .unwrap();
+    assert_eq!(answers.part2.as_deref(), Some("0"));
+    let error = solve(8, "nop +0\nacc +2147483647\nacc +1\njmp +0\n", Part::Two);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+    assert_eq!(location(&error), (Some(3), None));
+
+    let answers = aoc::solver(9, &Options { preamble: 2 })
+        .solve(
+            &lines("18446744073709551615\n18446744073709551615\n1\n"),
+            Part::One,
+        )
+        .unwrap();
+    assert_eq!(answers.part1.as_deref(), Some("1"));
+
+    let input = "mask = 00000000000000000000000000000000000X\nmem[0] = 18446744073709551615\n";
+    assert_eq!(
+        solve_both(14, input).unwrap_err().kind,
+        ErrorKind::NoSolution
+    );
+    let answers = aoc::solver(14, &Options::default())
+        .solve(&lines(input), Part::One)
+        .unwrap();
+    assert_eq!(answers.part1.as_deref(), Some("1"));
+
+    assert!(solve_both(16, "a: 1-18446744073709551615\n\nyour ticket:\n7\n").is_ok());
+
+    let error = solve(
+        18,
+        "1 + 2\n99999999999 * 99999999999 * 99999999999\n",
+        Part::One,
+    );
+    assert_eq!(location(&error), (Some(2), None));
+    let error = solve(18, "99999999999 * (99999999999 + 99999999999)\n", Part::Two);
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+}
+
+#[test]
+fn deep_inputs_are_answered_without_running_out_of_stack() {
+    let nested = format!("{}1{}\n", "(".repeat(200_000), ")".repeat(200_000));
+    let error = solve(18, &nested, Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+    assert_eq!(location(&error), (Some(1), Some(1)));
+
+    let mut chain = String::from("shiny gold bags contain 1 c0 bag.\n");
+    for n in 0..200_000 {
+        chain += &format!("c{} bags contain 1 c{} bag.\n", n, n + 1);
+    }
+    let lines: Vec<String> = chain.lines().map(String::from).collect();
+    let answers = aoc::solver(7, &Options::default())
+        .solve(&lines, Part::Two)
+        .unwrap();
+    assert_eq!(answers.part2.as_deref(), Some("200001"));
+}
+
+#[test]
+fn bags_inside_themselves_are_bad_input() {
+    let error = solve(
+        7,
+        "light red bags contain 1 shiny gold bag.\n\
+         shiny gold bags contain 1 dark red bag.\n\
+         dark red bags contain 2 shiny gold bags.\n",
+        Part::One,
+    );
+    assert_eq!(error.kind, ErrorKind::BadInput);
+    assert_eq!(location(&error), (Some(2), None));
+    assert!(error.cause.contains("shiny gold"), "{}", error);
+}
+
+#[test]
+fn unreadable_inputs_fail_only_their_day() {
+    let directory = TempDir::new("all");
+    fs::create_dir_all(directory.join("day02.txt")).unwrap();
+    fs::write(
+        directory.join("day01.txt"),
+        "1721\n979\n366\n299\n675\n1456\n",
+    )
+    .unwrap();
+    fs::write(directory.join("day03.txt"), "..#\n#..\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("all")
+        .arg(&*directory)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(ErrorKind::Io.exit_code()));
+    assert!(
+        stdout.contains("Day 1:") && stdout.contains("Day 3:"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("Day 2:"), "{}", stdout);
+    assert!(stderr.starts_with("Day 2: error: "), "{}", stderr);
+}
+
+#[test]
+fn bad_preambles_are_usage_errors() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["day", "9", "--preamble", "five", "-"])
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stderr.starts_with("error: Invalid value for '--preamble <PREAMBLE>'"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn every_format_checks_the_input_the_same_way() {
+    let lines: Vec<String> = vec![String::from("0,3,6"), String::from("1,2,3")];
+
+    let error = solve(15, "0,3,6\n1,2,3\n", Part::One);
+    assert_eq!(error.kind, ErrorKind::BadInput);
+
+    let error = aoc::run(15, &lines, Part::One, &Options::default()).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::BadInput);
+}
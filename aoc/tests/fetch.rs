@@ -0,0 +1,153 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use aoc::cache;
+use aoc::client::Client;
+use aoc_common::ErrorKind;
+
+mod common;
+mod server;
+
+use common::TempDir;
+use server::StandIn;
+
+const INPUT: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+fn puzzle_site() -> StandIn {
+    StandIn::start(|request| match request.path.as_str() {
+        "/2020/day/1/input" if request.header("Cookie") == Some("session=secret") => {
+            (200, INPUT.to_string())
+        }
+        "/2020/day/1/input" => (
+            400,
+            String::from(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+        ),
+        _ => (404, String::from("404 Not Found\n")),
+    })
+}
+
+#[test]
+fn inputs_are_fetched_into_the_cache() {
+    let site = puzzle_site();
+    let directory = TempDir::new("fetched");
+
+    let (path, fetched) =
+        cache::fetch(&Client::new(&site.url, "secret"), &directory, 1, false).unwrap();
+
+    assert!(fetched);
+    assert_eq!(path, directory.join("day01.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+
+    let requests = site.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert!(requests[0].body.is_empty());
+    assert!(requests[0]
+        .header("User-Agent")
+        .unwrap()
+        .starts_with("aoc2020/"));
+}
+
+#[test]
+fn cached_inputs_are_not_fetched_again() {
+    let site = puzzle_site();
+    let directory = TempDir::new("cached");
+    let client = Client::new(&site.url, "secret");
+
+    cache::fetch(&client, &directory, 1, false).unwrap();
+    let (_, fetched) = cache::fetch(&client, &directory, 1, false).unwrap();
+    assert!(!fetched);
+    assert_eq!(site.requests().len(), 1);
+
+    let (_, fetched) = cache::fetch(&client, &directory, 1, true).unwrap();
+    assert!(fetched);
+    assert_eq!(site.requests().len(), 2);
+}
+
+#[test]
+fn refusals_are_reported_and_not_cached() {
+    let site = puzzle_site();
+    let directory = TempDir::new("refused");
+
+    let error = cache::fetch(&Client::new(&site.url, "stale"), &directory, 1, false).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Remote);
+    assert!(
+        error
+            .cause
+            .contains("HTTP 400: Puzzle inputs differ by user."),
+        "{}",
+        error
+    );
+    assert!(!directory.join("day01.txt").exists());
+
+    let error = cache::fetch(&Client::new(&site.url, "secret"), &directory, 2, false).unwrap_err();
+    assert!(error.cause.contains("HTTP 404"), "{}", error);
+}
+
+fn aoc(directory: &Path, url: &str, session: Option<&str>) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command.env("AOC_CACHE_DIR", directory).env("AOC_URL", url);
+    match session {
+        Some(session) => command.env("AOC_SESSION", session),
+        None => command.env_remove("AOC_SESSION"),
+    };
+    command
+}
+
+#[test]
+fn the_runner_finds_fetched_inputs() {
+    let site = puzzle_site();
+    let directory = TempDir::new("runner");
+
+    let output = aoc(&directory, &site.url, Some("secret"))
+        .args(["fetch", "1"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = aoc(&directory, &site.url, None)
+        .args(["day", "1", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("\"514579\""));
+}
+
+#[test]
+fn fetching_needs_a_session() {
+    let site = puzzle_site();
+    let directory = TempDir::new("no-session");
+
+    let output = aoc(&directory, &site.url, None)
+        .args(["fetch", "1"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(ErrorKind::Config.exit_code()));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("AOC_SESSION"));
+    assert!(site.requests().is_empty());
+}
+
+#[test]
+fn running_an_unfetched_day_says_how_to_fetch_it() {
+    let directory = TempDir::new("unfetched");
+
+    let output = aoc(&directory, "http://127.0.0.1:9", None)
+        .args(["day", "3"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("aoc fetch 3"));
+}
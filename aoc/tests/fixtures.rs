@@ -0,0 +1,139 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aoc::{Options, DAYS};
+use aoc_common::{Answers, Part};
+
+struct Fixture {
+    day: u32,
+    input: PathBuf,
+    expected: Answers,
+    options: Options,
+}
+
+fn parse_expected(day: u32, input: PathBuf, sidecar: &Path) -> Fixture {
+    let mut expected = Answers::default();
+    let mut options = Options::default();
+
+    for line in fs::read_to_string(sidecar).unwrap().lines() {
+        let (key, value) = line
+            .split_once(':')
+            .unwrap_or_else(|| panic!("{}: bad line {:?}", sidecar.display(), line));
+        let value = value.trim().to_string();
+
+        match key {
+            "part1" => expected.part1 = Some(value),
+            "part2" => expected.part2 = Some(value),
+            "preamble" => options.preamble = usize::from_str(&value).unwrap(),
+            _ => panic!("{}: unknown key {:?}", sidecar.display(), key),
+        }
+    }
+
+    Fixture {
+        day,
+        input,
+        expected,
+        options,
+    }
+}
+
+fn fixtures() -> Vec<Fixture> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut fixtures = Vec::new();
+
+    for day in 1..=DAYS {
+        let mut inputs: Vec<PathBuf> = fs::read_dir(root.join(format!("day{:02}", day)))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let name = path.file_name().unwrap().to_str().unwrap();
+                name.starts_with("test") && name.ends_with(".txt")
+            })
+            .collect();
+        inputs.sort();
+
+        for input in inputs {
+            let sidecar = input.with_extension("expected");
+            if sidecar.exists() {
+                fixtures.push(parse_expected(day, input, &sidecar));
+            }
+        }
+    }
+
+    fixtures
+}
+
+#[test]
+fn every_day_has_a_fixture() {
+    let fixtures = fixtures();
+
+    for day in 1..=DAYS {
+        assert!(
+            fixtures.iter().any(|f| f.day == day),
+            "day {} has no fixture with expected answers",
+            day
+        );
+    }
+}
+
+fn part(fixture: &Fixture) -> Option<Part> {
+    match (&fixture.expected.part1, &fixture.expected.part2) {
+        (Some(_), Some(_)) => Some(Part::Both),
+        (Some(_), None) => Some(Part::One),
+        (None, Some(_)) => Some(Part::Two),
+        (None, None) => None,
+    }
+}
+
+/// Solves `fixture` from `lines`, describing what went wrong if the answers
+/// were not the expected ones.
+fn check(fixture: &Fixture, lines: &[String], part: Part) -> Option<String> {
+    match aoc::solver(fixture.day, &fixture.options).solve(lines, part) {
+        Ok(answers) if answers == fixture.expected => None,
+        Ok(answers) => Some(format!(
+            "{}: expected {:?}, got {:?}",
+            fixture.input.display(),
+            fixture.expected,
+            answers
+        )),
+        Err(error) => Some(format!("{}: {}", fixture.input.display(), error)),
+    }
+}
+
+#[test]
+fn fixture_answers() {
+    let mut failures = Vec::new();
+
+    for fixture in fixtures() {
+        let part = match part(&fixture) {
+            Some(part) => part,
+            None => continue,
+        };
+
+        println!("checking {}", fixture.input.display());
+        let lines = aoc_common::read_lines_from(fixture.input.to_str().unwrap()).unwrap();
+        failures.extend(check(&fixture, &lines, part));
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn fixture_answers_survive_browser_copies() {
+    let mut failures = Vec::new();
+
+    for fixture in fixtures() {
+        let part = match part(&fixture) {
+            Some(part) => part,
+            None => continue,
+        };
+
+        let text = fs::read_to_string(&fixture.input).unwrap();
+        let messy: String = text.lines().map(|line| format!("{} \r\n", line)).collect();
+        let (lines, _) = aoc_common::normalise(&format!("\u{feff}{}\r\n\r\n", messy));
+        failures.extend(check(&fixture, &lines, part));
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc::{Options, DAYS};
+use aoc_common::fuzz;
+use aoc_common::Rng;
+
+/// Generous, as the tests run in a debug build alongside each other.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Inputs that once crashed a parser, saved by `aoc fuzz --corpus`. Each
+/// file is named for its day, as in `day08-missing-operand.txt`.
+#[test]
+fn crashers_are_rejected_with_errors() {
+    let mut failures = Vec::new();
+
+    for entry in fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/crashers")).unwrap()
+    {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let day = name
+            .strip_prefix("day")
+            .and_then(|rest| rest.get(..2))
+            .and_then(|day| u32::from_str(day).ok())
+            .unwrap_or_else(|| panic!("{}: expected a name like dayNN-*.txt", name));
+        let input = fs::read(&path).unwrap();
+
+        if let Some(failure) = fuzz::run(&aoc::fuzz_target(day), &input, TIMEOUT) {
+            failures.push(format!("{}: {}", name, failure));
+        } else if aoc::solver(day, &Options::default())
+            .parse_only(&fuzz::lines(&input))
+            .is_ok()
+        {
+            failures.push(format!("{}: parsed without an error", name));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn parsers_survive_fuzzing() {
+    let mut failures = Vec::new();
+
+    for day in 1..=DAYS {
+        let target = aoc::fuzz_target(day);
+        if let Some(crash) = fuzz::fuzz(
+            &target,
+            &aoc::fuzz_seeds(day),
+            &mut Rng::new(0),
+            200,
+            TIMEOUT,
+        ) {
+            failures.push(format!(
+                "day {}: {}: {:?}",
+                day,
+                crash.failure,
+                String::from_utf8_lossy(&crash.input)
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
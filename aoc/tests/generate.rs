@@ -0,0 +1,97 @@
+use aoc::{Options, DAYS};
+use aoc_common::{Part, Rng};
+
+/// Small enough that every day solves quickly, even in a debug build.
+fn size(day: u32) -> usize {
+    match day {
+        11 => 10,
+        13 | 15 => 5,
+        17 => 3,
+        _ => 40,
+    }
+}
+
+#[test]
+fn generated_inputs_have_their_planted_answers() {
+    let options = Options::default();
+    let mut failures = Vec::new();
+
+    for day in (1..=DAYS).filter(|day| *day != 15) {
+        for seed in 0..5 {
+            let generated = aoc::generator(day, &options).generate(&mut Rng::new(seed), size(day));
+
+            match aoc::solver(day, &options).solve(&generated.lines, Part::Both) {
+                Ok(answers) => {
+                    let planted = [
+                        (generated.answers.part1, answers.part1),
+                        (generated.answers.part2, answers.part2),
+                    ];
+                    for (part, (expected, got)) in planted.iter().enumerate() {
+                        if expected.is_some() && expected != got {
+                            failures.push(format!(
+                                "day {} seed {} part {}: expected {:?}, got {:?}",
+                                day,
+                                seed,
+                                part + 1,
+                                expected,
+                                got
+                            ));
+                        }
+                    }
+                }
+                Err(error) => failures.push(format!("day {} seed {}: {}", day, seed, error)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn day15_inputs_parse() {
+    let generated = aoc::generator(15, &Options::default()).generate(&mut Rng::new(1), 6);
+
+    assert_eq!(generated.lines.len(), 1);
+    assert!(aoc::solver(15, &Options::default())
+        .solve(&generated.lines, Part::One)
+        .is_ok());
+}
+
+#[test]
+fn day9_inputs_stay_in_range_at_large_sizes() {
+    let options = Options::default();
+    let generated = aoc::generator(9, &options).generate(&mut Rng::new(3), 20000);
+
+    assert_eq!(generated.lines.len(), 20000);
+    let answers = aoc::solver(9, &options)
+        .solve(&generated.lines, Part::Both)
+        .unwrap();
+    assert_eq!(answers, generated.answers);
+}
+
+#[test]
+fn seeds_are_deterministic() {
+    let options = Options::default();
+
+    for day in 1..=DAYS {
+        let generator = aoc::generator(day, &options);
+        let first = generator.generate(&mut Rng::new(42), size(day));
+        let second = generator.generate(&mut Rng::new(42), size(day));
+        let other = generator.generate(&mut Rng::new(43), size(day));
+
+        assert_eq!(first.lines, second.lines, "day {}", day);
+        assert_eq!(first.answers, second.answers, "day {}", day);
+        assert_ne!(first.lines, other.lines, "day {}", day);
+    }
+}
+
+#[test]
+fn the_preamble_length_is_respected() {
+    let options = Options { preamble: 5 };
+    let generated = aoc::generator(9, &options).generate(&mut Rng::new(7), 30);
+    let answers = aoc::solver(9, &options)
+        .solve(&generated.lines, Part::Both)
+        .unwrap();
+
+    assert_eq!(answers, generated.answers);
+}
@@ -0,0 +1,168 @@
+//! Compares the grid dumps some days print with snapshots kept beside
+//! their inputs.
+//!
+//! Run with `BLESS=1` to overwrite the snapshots with the current output
+//! after a deliberate change to the rendering or the simulation.
+
+use std::env;
+use std::fs::{self, File};
+use std::path::Path;
+use std::process::{self, Command};
+
+use aoc_common::{Frame, Part};
+
+struct Golden {
+    /// The snapshot, relative to the workspace root.
+    snapshot: &'static str,
+    /// The arguments to `aoc`, which runs from the workspace root.
+    args: &'static [&'static str],
+}
+
+const GOLDEN: &[Golden] = &[
+    Golden {
+        snapshot: "day11/plain.txt",
+        args: &["day", "11", "day11/test1.txt"],
+    },
+    Golden {
+        snapshot: "day11/rounds.txt",
+        args: &["day", "11", "-vv", "day11/test1.txt"],
+    },
+    Golden {
+        snapshot: "day17/foo.txt",
+        args: &["day", "17", "-vv", "--part", "1", "day17/test1.txt"],
+    },
+];
+
+/// Runs `aoc` with standard output and standard error going to the same
+/// file, so that the trace and the answers interleave as on a terminal.
+fn output(golden: &Golden, root: &Path) -> Vec<u8> {
+    let name = golden.snapshot.replace('/', "-");
+    let capture = env::temp_dir().join(format!("aoc-golden-{}-{}", process::id(), name));
+    let file = File::create(&capture).unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(golden.args)
+        .current_dir(root)
+        .stdout(file.try_clone().unwrap())
+        .stderr(file)
+        .status()
+        .unwrap();
+    assert!(
+        status.success(),
+        "{}: aoc exited with {}",
+        golden.snapshot,
+        status
+    );
+
+    let output = fs::read(&capture).unwrap();
+    fs::remove_file(&capture).unwrap();
+    output
+}
+
+/// Describes the first line at which `got` and `expected` differ.
+fn first_difference(got: &str, expected: &str) -> String {
+    let mut got_lines = got.lines();
+    let mut expected_lines = expected.lines();
+
+    for line in 1.. {
+        match (got_lines.next(), expected_lines.next()) {
+            (Some(g), Some(e)) if g == e => continue,
+            (None, None) => break,
+            (g, e) => {
+                return format!(
+                    "line {}: expected {:?}, got {:?}",
+                    line,
+                    e.unwrap_or("<end of output>"),
+                    g.unwrap_or("<end of output>")
+                )
+            }
+        }
+    }
+
+    String::from("the line endings differ")
+}
+
+#[test]
+fn grid_dumps_match_their_snapshots() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let bless = env::var_os("BLESS").is_some();
+    let mut failures = Vec::new();
+
+    for golden in GOLDEN {
+        let path = root.join(golden.snapshot);
+        let got = output(golden, root);
+        let expected = fs::read(&path).unwrap_or_default();
+
+        if got == expected {
+            continue;
+        }
+
+        if bless {
+            fs::write(&path, &got).unwrap();
+            eprintln!("{}: updated", golden.snapshot);
+        } else {
+            failures.push(format!(
+                "{}: {}",
+                golden.snapshot,
+                first_difference(
+                    &String::from_utf8_lossy(&got),
+                    &String::from_utf8_lossy(&expected)
+                )
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\nrun with BLESS=1 to update the snapshots",
+        failures.join("\n")
+    );
+}
+
+/// The seating areas in `frames`, each followed by a blank line as day 11
+/// prints them.
+fn pictures(frames: &[Frame]) -> String {
+    frames
+        .iter()
+        .map(|frame| format!("{}\n", frame.picture))
+        .collect()
+}
+
+/// The grid dump kept from before these tests, in `day11/output.txt`, also
+/// shows every round of part 2. In between are lines from a debugging
+/// version of the neighbour search, such as `2, 0 d -1 0 @ 0 0` and
+/// `2, 0 -> 5`, which no code here prints any more. Without them it must
+/// still show the rounds the simulation goes through today.
+#[test]
+fn the_original_day11_capture_still_holds() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let lines: Vec<String> = fs::read_to_string(root.join("day11/test1.txt"))
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect();
+    let frames = |part| aoc::frames(11, &lines, part).unwrap().unwrap();
+    let (one, two) = (frames(Part::One), frames(Part::Two));
+    let occupied = |frames: &[Frame]| {
+        let last = &frames[frames.len() - 1].picture;
+        last.chars().filter(|c| *c == '#').count()
+    };
+
+    let got = format!(
+        "{}{}\n{} occupied\n\n{}{}\n{} occupied\n",
+        pictures(&two[..1]),
+        one[one.len() - 1].picture,
+        occupied(&one),
+        pictures(&two[1..]),
+        two[two.len() - 1].picture,
+        occupied(&two)
+    );
+    let original: String = fs::read_to_string(root.join("day11/output.txt"))
+        .unwrap()
+        .lines()
+        .filter(|line| !line.contains(" d ") && !line.contains(" -> "))
+        .map(|line| format!("{}\n", line))
+        .collect();
+
+    assert!(original == got, "{}", first_difference(&got, &original));
+}
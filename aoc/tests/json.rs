@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use aoc::Options;
+use aoc_common::serde_json::json;
+use aoc_common::Part;
+
+fn lines(day: u32, name: &str) -> Vec<String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let path = root.join(format!("day{:02}", day)).join(name);
+    aoc_common::read_lines_from(path.to_str().unwrap()).unwrap()
+}
+
+#[test]
+fn report_carries_answers_and_diagnostics() {
+    aoc_common::set_verbosity(aoc_common::Verbosity::Quiet);
+
+    let lines = lines(8, "test1.txt");
+    let report = aoc::solver(8, &Options::default())
+        .report(8, &lines, Part::Both, 3)
+        .unwrap();
+    let json = report.to_json();
+
+    assert_eq!(json["day"], json!(8));
+    assert_eq!(json["part1"], json!("5"));
+    assert_eq!(json["part2"], json!("8"));
+    assert_eq!(json["diagnostics"], json!({ "part2": { "tweakpoint": 7 } }));
+    assert_eq!(json["timings"]["runs"], json!(3));
+    for stage in &["parse", "part1", "part2"] {
+        let stats = &json["timings"][stage];
+        assert!(stats["min_ns"].as_u64().unwrap() <= stats["median_ns"].as_u64().unwrap());
+        assert!(stats["median_ns"].as_u64().unwrap() <= stats["max_ns"].as_u64().unwrap());
+    }
+}
+
+#[test]
+fn report_leaves_unrun_parts_null() {
+    aoc_common::set_verbosity(aoc_common::Verbosity::Quiet);
+
+    let lines = lines(10, "test1.txt");
+    let json = aoc::solver(10, &Options::default())
+        .report(10, &lines, Part::Two, 1)
+        .unwrap()
+        .to_json();
+
+    assert_eq!(json["part1"], json!(null));
+    assert_eq!(json["part2"], json!("8"));
+    assert_eq!(json["timings"]["part1"], json!(null));
+    assert_eq!(json["diagnostics"], json!({}));
+}
@@ -0,0 +1,237 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Once;
+
+use aoc::plugins::{self, Plugin};
+use aoc_common::plugin::{self, Position};
+use aoc_common::{ErrorKind, Part, Solver};
+
+mod common;
+
+use common::TempDir;
+
+const INPUT: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+/// Where the example plugin is built. It has a target directory of its
+/// own so that building it does not wait on the one running this test.
+fn build_directory() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("plugin-tests")
+}
+
+/// Builds the test plugins, once per run, and returns the path of the one
+/// whose library is called `name`.
+fn built_plugin(name: &str) -> PathBuf {
+    static BUILD: Once = Once::new();
+
+    BUILD.call_once(|| {
+        let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+        let status = Command::new(cargo)
+            .args(["build", "--quiet"])
+            .args(["--package", "day1-sorted", "--package", "day-out-of-range"])
+            .arg("--target-dir")
+            .arg(build_directory())
+            .status()
+            .unwrap();
+        assert!(status.success(), "the test plugins did not build");
+    });
+
+    build_directory().join("debug").join(format!(
+        "{}{}{}",
+        env::consts::DLL_PREFIX,
+        name,
+        env::consts::DLL_SUFFIX
+    ))
+}
+
+/// The example day 1 plugin.
+fn example_plugin() -> PathBuf {
+    built_plugin("day1_sorted")
+}
+
+/// A directory holding just the example plugin.
+fn plugin_directory(name: &str) -> TempDir {
+    let directory = TempDir::new(name);
+
+    let plugin = example_plugin();
+    fs::copy(&plugin, directory.join(plugin.file_name().unwrap())).unwrap();
+    directory
+}
+
+fn lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+#[test]
+fn plugins_register_what_they_solve() {
+    let plugin = Plugin::load(&example_plugin()).unwrap();
+
+    assert_eq!(plugin.day, 1);
+    assert_eq!(plugin.name, "sorted");
+    assert_eq!(plugin.input_format, "one expense entry per line");
+    assert!(plugin.solves(1));
+    assert!(plugin.solves(2));
+    assert_eq!(plugin.part(Part::Both), Part::Both);
+}
+
+#[test]
+fn plugins_agree_with_the_built_in_solver() {
+    let plugin = Plugin::load(&example_plugin()).unwrap();
+    let lines = lines(INPUT);
+
+    assert_eq!(
+        plugin.solve(&lines, Part::Both).unwrap(),
+        aoc::solver(1, &Default::default())
+            .solve(&lines, Part::Both)
+            .unwrap()
+    );
+}
+
+#[test]
+fn plugin_errors_keep_their_kind_and_position() {
+    let plugin = Plugin::load(&example_plugin()).unwrap();
+
+    let error = plugin.solve(&lines("1721\n97x\n"), Part::One).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::BadInput);
+    assert_eq!((error.line, error.column), (Some(2), Some(1)));
+
+    let error = plugin.solve(&lines("1\n2\n"), Part::One).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+
+    let error = plugin
+        .solve(&lines("2021\n4294967295\n"), Part::One)
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::NoSolution);
+}
+
+#[test]
+fn plugin_codes_map_to_their_own_kinds() {
+    let kind = |code| plugin::error(code, "message", Position::default()).kind;
+
+    assert_eq!(kind(2), ErrorKind::NoSolution);
+    assert_eq!(kind(65), ErrorKind::BadInput);
+    assert_eq!(kind(74), ErrorKind::Io);
+    assert_eq!(kind(plugin::ERR_USAGE), ErrorKind::Config);
+    assert_eq!(kind(plugin::ERR_PANIC), ErrorKind::Internal);
+    assert_eq!(kind(plugin::ERR_BUFFER), ErrorKind::Internal);
+    assert_eq!(kind(123), ErrorKind::Internal);
+
+    let error = plugin::error(
+        plugin::ERR_PANIC,
+        "the solver panicked",
+        Position::default(),
+    );
+    assert_eq!(error.exit_code(), 70);
+    assert_eq!(error.cause, "the plugin panicked: the solver panicked");
+}
+
+#[test]
+fn days_after_the_event_are_refused() {
+    let error = Plugin::load(&built_plugin("day_out_of_range"))
+        .err()
+        .unwrap();
+
+    assert_eq!(error.kind, ErrorKind::Config);
+    assert!(
+        error.cause.starts_with("registers day 4000000000"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn directories_are_searched_for_plugins() {
+    let directory = plugin_directory("discover");
+    fs::write(directory.join("README.txt"), "not a plugin").unwrap();
+
+    let found = plugins::discover(&directory).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name, "sorted");
+
+    assert!(plugins::discover(&directory.join("missing"))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn libraries_that_are_not_plugins_are_skipped() {
+    let directory = plugin_directory("broken");
+    let broken = directory.join(format!("broken{}", env::consts::DLL_SUFFIX));
+    fs::write(&broken, "not a library").unwrap();
+
+    let error = Plugin::load(&broken).err().unwrap();
+    assert_eq!(error.kind, ErrorKind::Config);
+    assert!(error
+        .file
+        .unwrap()
+        .ends_with(&format!("broken{}", env::consts::DLL_SUFFIX)));
+
+    let found = plugins::discover(&directory).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name, "sorted");
+
+    let input = directory.join("input.txt");
+    fs::write(&input, INPUT).unwrap();
+    let run = |solver: Option<&str>| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+        command
+            .args(["day", "1", "--part", "1"])
+            .arg(&input)
+            .env(plugins::PLUGIN_VAR, &*directory);
+        if let Some(solver) = solver {
+            command.args(["--solver", solver]);
+        }
+        command.output().unwrap()
+    };
+
+    for solver in [None, Some("sorted")] {
+        let output = run(solver);
+        assert!(output.status.success(), "{:?}", output);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.starts_with("skipping plugin "), "{}", stderr);
+        assert!(stderr.contains("broken"), "{}", stderr);
+    }
+
+    let output = run(Some("broken"));
+    assert_eq!(output.status.code(), Some(78));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .ends_with("error: no plugin named broken for day 1\n"));
+}
+
+#[test]
+fn the_runner_uses_a_plugin_by_name() {
+    let directory = plugin_directory("runner");
+    let input = directory.join("input.txt");
+    fs::write(&input, INPUT).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["day", "1"])
+        .arg(&input)
+        .args(["--solver", "sorted"])
+        .env(plugins::PLUGIN_VAR, &*directory)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Part 1: 514579\nPart 2: 241861950\n"
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["day", "19"])
+        .arg(&input)
+        .env(plugins::PLUGIN_VAR, &*directory)
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(78));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: no solver for day 19; add a plugin for it to the plugin directory\n"
+    );
+}
@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+
+use common::TempDir;
+
+/// A directory of inputs: two examples and one that does not parse.
+fn inputs(name: &str) -> TempDir {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let directory = TempDir::new(name);
+
+    for day in &["01", "11"] {
+        fs::copy(
+            root.join(format!("day{}/test1.txt", day)),
+            directory.join(format!("day{}.txt", day)),
+        )
+        .unwrap();
+    }
+    fs::write(directory.join("day03.txt"), "<|>\n").unwrap();
+
+    directory
+}
+
+fn report(directory: &Path, format: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["report", "--format", format])
+        .arg(directory)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+const SETTLED: &str = "#.#L.L#.##\n#LLL#LL.L#\nL.#.L..#..\n#L##.##.L#\n#.#L.LL.LL\n\
+                       #.#L#L#.##\n..L.L.....\n#L#L##L#L#\n#.LLLLLL.L\n#.#L#L#.##";
+
+#[test]
+fn markdown_pages_have_a_row_per_input() {
+    let page = report(&inputs("markdown"), "markdown");
+    let rows: Vec<&str> = page.lines().filter(|line| line.starts_with("| ")).collect();
+
+    assert_eq!(rows.len(), 4);
+    assert!(
+        rows[1].starts_with("| 1 | 514579 | 241861950 | "),
+        "{}",
+        rows[1]
+    );
+    assert!(rows[1].ends_with(" | 6 lines, 26 bytes |"), "{}", rows[1]);
+    assert!(rows[2].starts_with("| 3 | error: "), "{}", rows[2]);
+    assert!(rows[2].contains("unexpected '<'"), "{}", rows[2]);
+    assert!(rows[3].starts_with("| 11 | 37 | 26 | "), "{}", rows[3]);
+
+    assert!(page.contains("No input for days 2, 4, 5,"));
+    assert!(page.contains(&format!("## Day 11, Part 1\n\n```text\n{}\n```", SETTLED)));
+}
+
+#[test]
+fn html_pages_escape_what_they_show() {
+    let page = report(&inputs("html"), "html");
+
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.contains("<tr><td>1</td><td>514579</td><td>241861950</td>"));
+    assert!(page.contains("unexpected '&lt;'"));
+    assert!(page.contains(&format!("<h2>Day 11, Part 1</h2>\n<pre>{}</pre>", SETTLED)));
+}
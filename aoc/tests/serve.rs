@@ -0,0 +1,144 @@
+use std::io::Write;
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+use aoc::serve::{Config, Server};
+use aoc_common::serde_json::{self, Value};
+
+const INPUT: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+/// Starts a server on a free port and returns its URL.
+fn start(config: Config) -> String {
+    let server = Server::bind("127.0.0.1:0", config).unwrap();
+    let url = format!("http://{}", server.local_addr().unwrap());
+    thread::spawn(move || server.run());
+    url
+}
+
+/// Makes a request, returning the status and the JSON body whatever the
+/// status was.
+fn request(method: &str, url: &str, body: &str) -> (u16, Value) {
+    let response = match ureq::request(method, url).send_string(body) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => panic!("{}: {}", url, e),
+    };
+
+    assert_eq!(response.content_type(), "application/json");
+    let status = response.status();
+    let body = response.into_string().unwrap();
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+#[test]
+fn inputs_are_answered_with_a_report() {
+    let url = start(Config::default());
+
+    let (status, report) = request("POST", &format!("{}/day/1", url), INPUT);
+
+    assert_eq!(status, 200);
+    assert_eq!(report["day"], 1);
+    assert_eq!(report["part1"], "514579");
+    assert_eq!(report["part2"], "241861950");
+    assert!(report["timings"]["parse"].is_object());
+}
+
+#[test]
+fn the_query_selects_a_part() {
+    let url = start(Config::default());
+
+    let (status, report) = request("POST", &format!("{}/day/1?part=1&runs=3", url), INPUT);
+
+    assert_eq!(status, 200);
+    assert_eq!(report["part1"], "514579");
+    assert!(report["part2"].is_null());
+
+    let (status, error) = request("POST", &format!("{}/day/1?part=3", url), INPUT);
+    assert_eq!(status, 400);
+    assert_eq!(error["error"]["cause"], "part must be 1 or 2");
+}
+
+#[test]
+fn bad_input_says_where() {
+    let url = start(Config::default());
+
+    let (status, error) = request("POST", &format!("{}/day/1", url), "1721\n97x\n");
+
+    assert_eq!(status, 400);
+    assert_eq!(error["error"]["kind"], "bad_input");
+    assert_eq!(error["error"]["line"], 2);
+    assert_eq!(error["error"]["column"], 1);
+}
+
+#[test]
+fn unknown_paths_and_methods_are_refused() {
+    let url = start(Config::default());
+
+    assert_eq!(request("POST", &format!("{}/day/26", url), INPUT).0, 404);
+    assert_eq!(request("POST", &format!("{}/nowhere", url), INPUT).0, 404);
+    assert_eq!(request("GET", &format!("{}/day/1", url), "").0, 405);
+
+    let (status, usage) = request("GET", &format!("{}/", url), "");
+    assert_eq!(status, 200);
+    assert_eq!(usage["days"], aoc::DAYS);
+}
+
+#[test]
+fn long_solves_time_out() {
+    let url = start(Config {
+        timeout: Duration::from_millis(50),
+        max_running: 1,
+        ..Config::default()
+    });
+
+    let (status, error) = request("POST", &format!("{}/day/15?part=2", url), "0,3,6\n");
+    assert_eq!(status, 504);
+    assert_eq!(error["error"]["kind"], "timeout");
+
+    // The abandoned solve still holds the only slot.
+    let (status, error) = request("POST", &format!("{}/day/1", url), INPUT);
+    assert_eq!(status, 503);
+    assert_eq!(error["error"]["kind"], "busy");
+}
+
+#[test]
+fn slow_uploads_hold_only_their_worker() {
+    let url = start(Config {
+        workers: 1,
+        ..Config::default()
+    });
+    let address = url.strip_prefix("http://").unwrap();
+
+    // Promise a body and never send it, which keeps the only worker busy.
+    // Small bodies are read before the request reaches a worker, so this
+    // one has to be large.
+    let mut stalled = TcpStream::connect(address).unwrap();
+    stalled
+        .write_all(b"POST /day/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 1000000\r\n\r\n")
+        .unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_millis(200))
+        .build();
+    assert!(agent.get(&format!("{}/", url)).call().is_err());
+
+    drop(stalled);
+    let (status, _) = request("GET", &format!("{}/", url), "");
+    assert_eq!(status, 200);
+}
+
+#[test]
+fn deeply_nested_inputs_leave_the_server_running() {
+    let url = start(Config::default());
+    let nested = format!("{}1{}\n", "(".repeat(50_000), ")".repeat(50_000));
+
+    let (status, error) = request("POST", &format!("{}/day/18", url), &nested);
+    assert_eq!(status, 400);
+    assert_eq!(error["error"]["kind"], "bad_input");
+
+    let (status, report) = request("POST", &format!("{}/day/1", url), INPUT);
+    assert_eq!(status, 200);
+    assert_eq!(report["part1"], "514579");
+}
@@ -0,0 +1,193 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+use aoc::client::Client;
+use aoc::submit::{self, Attempt, History, Outcome};
+use aoc_common::ErrorKind;
+
+mod common;
+mod server;
+
+use common::TempDir;
+use server::StandIn;
+
+const RIGHT: &str =
+    "That's the right answer! You are one gold star closer to saving your vacation.";
+const TOO_HIGH: &str = "That's not the right answer; your answer is too high. If you're stuck, \
+                        make sure you're using the full input data. Please wait one minute \
+                        before trying again.";
+const TOO_SOON: &str = "You gave an answer too recently; you have to wait after submitting an \
+                        answer before trying again. You have 1m 5s left to wait.";
+
+/// Wraps `message` the way the site does.
+fn page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        message
+    )
+}
+
+/// A stand-in that replies to each submission with the next of `replies`.
+fn puzzle_site(replies: &[&str]) -> StandIn {
+    let replies = Arc::new(Mutex::new(
+        replies.iter().map(|r| page(r)).collect::<Vec<_>>(),
+    ));
+    StandIn::start(move |_| (200, replies.lock().unwrap().remove(0)))
+}
+
+#[test]
+fn replies_are_understood() {
+    let right = Attempt::from_reply(13, 2, "1068781", 1000, RIGHT);
+    assert_eq!(right.outcome, Outcome::Correct);
+    assert_eq!(right.wait_until, None);
+
+    let too_high = Attempt::from_reply(13, 2, "1068781", 1000, TOO_HIGH);
+    assert_eq!(
+        too_high.outcome,
+        Outcome::Wrong {
+            hint: Some(String::from("too high"))
+        }
+    );
+    assert_eq!(too_high.wait_until, Some(1060));
+
+    let too_soon = Attempt::from_reply(13, 2, "1068781", 1000, TOO_SOON);
+    assert_eq!(too_soon.outcome, Outcome::TooSoon);
+    assert_eq!(too_soon.wait_until, Some(1065));
+
+    // Waits that cannot be read fall back to a minute rather than failing.
+    for wait in ["5é", "99999999999999999999h", "3000000000000000000h"] {
+        let message = format!(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again. You have {} left to wait.",
+            wait
+        );
+        let attempt = Attempt::from_reply(13, 2, "1", 1000, &message);
+        assert_eq!(attempt.wait_until, Some(1060), "{}", wait);
+    }
+}
+
+#[test]
+fn attempts_are_posted_and_recorded() {
+    let site = puzzle_site(&[RIGHT]);
+    let directory = TempDir::new("recorded");
+    let path = directory.join(History::FILE_NAME);
+    let client = Client::new(&site.url, "secret");
+
+    let mut history = History::load(&path).unwrap();
+    let attempt = submit::submit(&client, &mut history, 13, 2, "1068781", 1000).unwrap();
+    assert_eq!(attempt.outcome, Outcome::Correct);
+    assert_eq!(attempt.message, RIGHT);
+
+    let requests = site.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2020/day/13/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=2&answer=1068781");
+
+    let reloaded = History::load(&path).unwrap();
+    assert_eq!(reloaded.attempts(), &[attempt]);
+}
+
+#[test]
+fn known_wrong_answers_are_not_resubmitted() {
+    let site = puzzle_site(&[TOO_HIGH, RIGHT]);
+    let directory = TempDir::new("wrong");
+    let path = directory.join(History::FILE_NAME);
+    let client = Client::new(&site.url, "secret");
+    let mut history = History::load(&path).unwrap();
+
+    submit::submit(&client, &mut history, 10, 2, "500", 1000).unwrap();
+
+    let mut history = History::load(&path).unwrap();
+    for answer in &["500", "501"] {
+        let error = submit::submit(&client, &mut history, 10, 2, answer, 5000).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Refused, "{}", answer);
+    }
+    assert_eq!(site.requests().len(), 1);
+
+    // The hint only rules out answers on the wrong side of it.
+    let attempt = submit::submit(&client, &mut history, 10, 2, "499", 5000).unwrap();
+    assert_eq!(attempt.outcome, Outcome::Correct);
+
+    let error = submit::submit(&client, &mut history, 10, 2, "498", 5000).unwrap_err();
+    assert!(error.cause.contains("already solved with 499"), "{}", error);
+    assert_eq!(site.requests().len(), 2);
+}
+
+#[test]
+fn cooldowns_are_respected() {
+    let site = puzzle_site(&[TOO_SOON, RIGHT]);
+    let directory = TempDir::new("cooldown");
+    let path = directory.join(History::FILE_NAME);
+    let client = Client::new(&site.url, "secret");
+    let mut history = History::load(&path).unwrap();
+
+    let attempt = submit::submit(&client, &mut history, 7, 1, "4", 1000).unwrap();
+    assert_eq!(attempt.outcome, Outcome::TooSoon);
+
+    let error = submit::submit(&client, &mut history, 7, 1, "4", 1064).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Refused);
+    assert!(error.cause.contains("again in 1s"), "{}", error);
+
+    // The wait is for the account, so other days are held up too.
+    let error = history.check(8, 1, "5", 1064).unwrap_err();
+    assert!(error.cause.contains("again in 1s"), "{}", error);
+
+    let attempt = submit::submit(&client, &mut history, 7, 1, "4", 1065).unwrap();
+    assert_eq!(attempt.outcome, Outcome::Correct);
+    assert_eq!(site.requests().len(), 2);
+}
+
+#[test]
+fn the_command_submits_the_solvers_answer() {
+    let site = puzzle_site(&[RIGHT]);
+    let directory = TempDir::new("command");
+    let history = directory.join(History::FILE_NAME);
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "1", "2"])
+        .arg(root.join("day01/test1.txt"))
+        .env("AOC_CACHE_DIR", &*directory)
+        .env("AOC_URL", &site.url)
+        .env("AOC_SESSION", "secret")
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 1 part 2: 241861950 is correct\n"
+    );
+    assert_eq!(site.requests()[0].body, "level=2&answer=241861950");
+    assert_eq!(History::load(&history).unwrap().attempts().len(), 1);
+}
+
+#[test]
+fn the_command_fails_on_a_wrong_answer() {
+    let site = puzzle_site(&[TOO_HIGH]);
+    let directory = TempDir::new("command-wrong");
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "1", "1"])
+        .arg(root.join("day01/test1.txt"))
+        .env("AOC_CACHE_DIR", &*directory)
+        .env("AOC_URL", &site.url)
+        .env("AOC_SESSION", "secret")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(ErrorKind::Failed.exit_code()));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.ends_with("error: the answer was not accepted\n"),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,44 @@
+use std::fmt::Display;
+
+use serde_json::Value;
+
+/// The answer to one part of a day.
+///
+/// `Display` gives the value to submit; anything else worth reporting about
+/// how it was reached goes in `diagnostics`, and a day whose answer comes
+/// from a picture can draw it in `visualisation`.
+pub trait Answer: Display {
+    fn diagnostics(&self) -> Option<Value> {
+        None
+    }
+
+    /// A plain-text drawing of the state the answer was read from.
+    fn visualisation(&self) -> Option<String> {
+        None
+    }
+}
+
+/// One picture in a sequence that shows how an answer was reached, for
+/// stepping through.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub title: String,
+    pub picture: String,
+}
+
+impl Frame {
+    pub fn new(title: impl Into<String>, picture: &impl Display) -> Self {
+        Self {
+            title: title.into(),
+            picture: picture.to_string(),
+        }
+    }
+}
+
+macro_rules! plain_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {})*
+    };
+}
+
+plain_answer!(i32, i64, u32, u64, usize, String);
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::{Part, Result, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub(crate) fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    /// Summarises samples for a part that may not have been run.
+    pub(crate) fn from_optional_samples(samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            None
+        } else {
+            Some(Self::from_samples(samples))
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Timings {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Timings {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "runs": self.runs,
+            "parse": self.parse.to_json(),
+            "part1": self.part1.map(|stats| stats.to_json()),
+            "part2": self.part2.map(|stats| stats.to_json()),
+        })
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} runs", self.runs)?;
+        writeln!(f, "parse   {}", self.parse)?;
+        if let Some(stats) = self.part1 {
+            writeln!(f, "part 1  {}", stats)?;
+        }
+        if let Some(stats) = self.part2 {
+            writeln!(f, "part 2  {}", stats)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn time<T>(samples: &mut Vec<Duration>, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = black_box(f());
+    samples.push(start.elapsed());
+    result
+}
+
+/// Times each stage of `solution` separately over `runs` repetitions.
+pub fn bench<S: Solution>(
+    solution: &S,
+    lines: &[String],
+    part: Part,
+    runs: usize,
+) -> Result<Timings> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let input = time(&mut parse, || solution.parse(lines))?;
+
+        if part.one() {
+            time(&mut part1, || solution.part1(&input))?;
+        }
+
+        if part.two() {
+            time(&mut part2, || solution.part2(&input))?;
+        }
+    }
+
+    Ok(Timings {
+        runs,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_optional_samples(part1),
+        part2: Stats::from_optional_samples(part2),
+    })
+}
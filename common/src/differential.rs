@@ -0,0 +1,100 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::Rng;
+
+/// Two ways of computing the same thing, to be compared on random inputs.
+///
+/// Either side may be a slow reference that follows the puzzle text as
+/// literally as possible.
+pub trait Differential {
+    const NAME: &'static str;
+    const LEFT: &'static str;
+    const RIGHT: &'static str;
+
+    type Input: Clone + fmt::Debug;
+    type Output: PartialEq + fmt::Debug;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Input;
+
+    /// Inputs a little smaller than `input`, to try in turn when looking
+    /// for the smallest input the two sides disagree on.
+    fn shrink(&self, input: &Self::Input) -> Vec<Self::Input>;
+
+    fn left(&self, input: &Self::Input) -> Self::Output;
+    fn right(&self, input: &Self::Input) -> Self::Output;
+}
+
+/// An input the two sides of a differential gave different outputs for.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub name: &'static str,
+    /// The seed whose input first showed the disagreement.
+    pub seed: u64,
+    /// The smallest input found that still shows it.
+    pub input: String,
+    pub left: (&'static str, String),
+    pub right: (&'static str, String),
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: disagreement from seed {}", self.name, self.seed)?;
+        writeln!(f, "  input: {}", self.input)?;
+        writeln!(f, "  {}: {}", self.left.0, self.left.1)?;
+        write!(f, "  {}: {}", self.right.0, self.right.1)
+    }
+}
+
+/// An object-safe view of a `Differential`, for running them in bulk.
+pub trait Check {
+    fn name(&self) -> &'static str;
+
+    /// Tries an input from each seed, growing from size 1 to `max_size`
+    /// and starting again, and shrinks the first one the two sides
+    /// disagree on.
+    fn check(&self, seeds: Range<u64>, max_size: usize) -> Option<Disagreement>;
+}
+
+impl<D: Differential> Check for D {
+    fn name(&self) -> &'static str {
+        D::NAME
+    }
+
+    fn check(&self, seeds: Range<u64>, max_size: usize) -> Option<Disagreement> {
+        let disagrees = |input: &D::Input| self.left(input) != self.right(input);
+
+        for seed in seeds {
+            let size = 1 + (seed as usize) % max_size.max(1);
+            let mut input = self.generate(&mut Rng::new(seed), size);
+            if !disagrees(&input) {
+                continue;
+            }
+
+            while let Some(smaller) = self.shrink(&input).into_iter().find(|i| disagrees(i)) {
+                input = smaller;
+            }
+
+            return Some(Disagreement {
+                name: D::NAME,
+                seed,
+                input: format!("{:?}", input),
+                left: (D::LEFT, format!("{:?}", self.left(&input))),
+                right: (D::RIGHT, format!("{:?}", self.right(&input))),
+            });
+        }
+
+        None
+    }
+}
+
+/// The ways of removing one item from `items`.
+pub fn removals<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    (0..items.len())
+        .map(|i| {
+            let mut smaller = items.to_vec();
+            smaller.remove(i);
+            smaller
+        })
+        .collect()
+}
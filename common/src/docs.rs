@@ -0,0 +1,156 @@
+//! Shell completions and man pages, which every binary can print about
+//! itself.
+//!
+//! clap 2 has no man page generator, so a page is made from the same help
+//! text that `--help` prints, one section per subcommand.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::process;
+
+use clap::{App, AppSettings, Arg, ArgMatches, Shell, SubCommand};
+
+/// The shells completions can be generated for.
+const SHELLS: [&str; 3] = ["bash", "zsh", "fish"];
+
+/// The `completions` and `man` subcommands.
+pub fn subcommands() -> Vec<App<'static, 'static>> {
+    vec![
+        SubCommand::with_name("completions")
+            .about("Prints a shell completion script")
+            .arg(
+                Arg::with_name("SHELL")
+                    .help("The shell to complete for")
+                    .required(true)
+                    .possible_values(&SHELLS)
+                    .index(1),
+            ),
+        SubCommand::with_name("man").about("Prints a man page"),
+    ]
+}
+
+/// Adds the `completions` and `man` subcommands to `app`, for binaries
+/// whose positional arguments are inputs.
+///
+/// A subcommand is only looked for before any other argument, so an input
+/// named like one can still be given after a flag or as `./man`, and there
+/// is no `help` subcommand to shadow an input named `help`. Required
+/// arguments are not needed alongside a subcommand.
+pub fn with_subcommands(app: App<'static, 'static>) -> App<'static, 'static> {
+    app.setting(AppSettings::ArgsNegateSubcommands)
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .setting(AppSettings::DisableHelpSubcommand)
+        .subcommands(subcommands())
+}
+
+/// Parses the command line of `app`, answering the `completions` and `man`
+/// subcommands and exiting if either was given.
+pub fn get_matches(app: App<'static, 'static>) -> ArgMatches<'static> {
+    let mut docs = app.clone();
+    let matches = app.get_matches();
+    let bin = docs
+        .get_bin_name()
+        .unwrap_or_else(|| docs.get_name())
+        .to_string();
+
+    match matches.subcommand() {
+        ("completions", Some(completions)) => {
+            let shell = completions.value_of("SHELL").unwrap();
+            docs.gen_completions_to(bin, shell.parse::<Shell>().unwrap(), &mut io::stdout());
+        }
+        ("man", Some(_)) => print!("{}", man_page(&docs, &bin)),
+        _ => return matches,
+    }
+
+    let _ = io::stdout().flush();
+    process::exit(0);
+}
+
+/// Makes `text` safe to put in a roff no-fill block.
+fn escape(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let line = line.trim_end().replace('\\', "\\e");
+            if line.starts_with('.') || line.starts_with('\'') {
+                format!("\\&{}\n", line)
+            } else {
+                format!("{}\n", line)
+            }
+        })
+        .collect()
+}
+
+/// `app` rendered through a help `template`, without wrapping.
+fn render(app: &App<'static, 'static>, template: &'static str) -> String {
+    let mut text = Vec::new();
+    app.clone()
+        .template(template)
+        .set_term_width(0)
+        .write_help(&mut text)
+        .expect("failed to write help");
+    String::from_utf8_lossy(&text).into_owned()
+}
+
+/// The help text of `app`, as `--help` prints it for `bin`.
+fn help(app: &App<'static, 'static>, bin: &str) -> String {
+    let mut text = Vec::new();
+    app.clone()
+        .bin_name(bin)
+        .write_long_help(&mut text)
+        .expect("failed to write help");
+    String::from_utf8_lossy(&text).into_owned()
+}
+
+/// The help text of `app`'s subcommand `name`, as `bin name --help` prints
+/// it.
+fn subcommand_help(app: &App<'static, 'static>, bin: &str, name: &str) -> String {
+    match app.clone().get_matches_from_safe([bin, name, "--help"]) {
+        Err(error) if error.kind == clap::ErrorKind::HelpDisplayed => error.message,
+        _ => panic!("{} {} --help printed no help", bin, name),
+    }
+}
+
+/// A man page for `app`, installed as `bin`.
+pub fn man_page(app: &App<'static, 'static>, bin: &str) -> String {
+    // clap fills in a placeholder for a missing description, which an app
+    // without one shows.
+    let about = render(app, "{about}");
+    let about = if about == render(&App::new(""), "{about}") {
+        app.get_name()
+    } else {
+        about.trim()
+    };
+    // Each subcommand's line in the list starts with its name. The `help`
+    // subcommand clap adds has nothing of its own to say.
+    let subcommands: Vec<String> = render(app, "{subcommands}")
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|name| *name != "help")
+        .map(String::from)
+        .collect();
+    let mut page = String::new();
+
+    writeln!(
+        page,
+        ".TH \"{}\" \"1\" \"\" \"aoc2020\" \"Advent of Code 2020\"",
+        bin.to_uppercase()
+    )
+    .unwrap();
+    writeln!(page, ".SH NAME\n{} \\- {}", bin, escape(about).trim_end()).unwrap();
+    writeln!(page, ".SH DESCRIPTION\n.nf\n{}.fi", escape(&help(app, bin))).unwrap();
+
+    if !subcommands.is_empty() {
+        writeln!(page, ".SH SUBCOMMANDS").unwrap();
+    }
+    for subcommand in subcommands {
+        writeln!(page, ".SS \"{} {}\"", bin, subcommand).unwrap();
+        writeln!(
+            page,
+            ".nf\n{}.fi",
+            escape(&subcommand_help(app, bin, &subcommand))
+        )
+        .unwrap();
+    }
+
+    page
+}
@@ -0,0 +1,216 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::process;
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input could not be parsed.
+    BadInput,
+    /// The input could not be read.
+    Io,
+    /// The input parsed but has no answer, or one too large to work out.
+    NoSolution,
+    /// The puzzle website could not be reached or turned a request down.
+    Remote,
+    /// A setting that a command needs is missing.
+    Config,
+    /// A request to the puzzle website was held back to keep to its rules.
+    Refused,
+    /// A solver went wrong in itself, such as a plugin that panicked.
+    Internal,
+    /// A check ran and failed, such as paired solvers disagreeing, a fuzzed
+    /// input crashing a parser or the puzzle website turning an answer down.
+    Failed,
+}
+
+impl ErrorKind {
+    /// The process exit code used for this kind of error.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorKind::BadInput => 65,
+            ErrorKind::Io => 74,
+            ErrorKind::NoSolution => 2,
+            ErrorKind::Remote => 69,
+            ErrorKind::Config => 78,
+            ErrorKind::Refused => 77,
+            ErrorKind::Internal => 70,
+            ErrorKind::Failed => 3,
+        }
+    }
+
+    /// The kind of error that exits with `code`, if any does.
+    pub fn from_exit_code(code: i32) -> Option<Self> {
+        [
+            ErrorKind::BadInput,
+            ErrorKind::Io,
+            ErrorKind::NoSolution,
+            ErrorKind::Remote,
+            ErrorKind::Config,
+            ErrorKind::Refused,
+            ErrorKind::Internal,
+            ErrorKind::Failed,
+        ]
+        .iter()
+        .copied()
+        .find(|kind| kind.exit_code() == code)
+    }
+}
+
+/// An error from reading, parsing or solving a day's input.
+///
+/// Line and column numbers start at 1. Each is filled in by whichever layer
+/// knows it, so a line parser only reports a column and its caller adds the
+/// line.
+#[derive(Debug)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub cause: String,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, cause: impl Into<String>) -> Self {
+        Self {
+            kind,
+            file: None,
+            line: None,
+            column: None,
+            cause: cause.into(),
+        }
+    }
+
+    pub fn bad_input(cause: impl Into<String>) -> Self {
+        Self::new(ErrorKind::BadInput, cause)
+    }
+
+    pub fn no_solution(cause: impl Into<String>) -> Self {
+        Self::new(ErrorKind::NoSolution, cause)
+    }
+
+    /// An error for a well-formed input whose `what` grows too large to
+    /// hold while working out the answer.
+    pub fn too_large(what: &str) -> Self {
+        Self::new(
+            ErrorKind::NoSolution,
+            format!("{} is too large to work out", what),
+        )
+    }
+
+    pub fn remote(cause: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Remote, cause)
+    }
+
+    pub fn config(cause: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Config, cause)
+    }
+
+    pub fn refused(cause: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Refused, cause)
+    }
+
+    pub fn internal(cause: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Internal, cause)
+    }
+
+    pub fn failed(cause: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Failed, cause)
+    }
+
+    /// Sets the file name, unless one is already set.
+    ///
+    /// A file name of `-` is reported as standard input.
+    pub fn file(mut self, file: &str) -> Self {
+        self.file
+            .get_or_insert_with(|| crate::display_path(file).to_string());
+        self
+    }
+
+    /// Sets the line number, unless one is already set.
+    pub fn line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column number, unless one is already set.
+    pub fn column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        self.kind.exit_code()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location: Vec<String> = vec![
+            self.file.clone(),
+            self.line.map(|line| line.to_string()),
+            self.column.map(|column| column.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if location.is_empty() {
+            write!(f, "{}", self.cause)
+        } else {
+            write!(f, "{}: {}", location.join(":"), self.cause)
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::new(ErrorKind::Io, error.to_string())
+    }
+}
+
+/// Returns the column at which `field`, a slice of `line`, starts.
+pub fn column(line: &str, field: &str) -> usize {
+    let offset = (field.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() {
+        offset + 1
+    } else {
+        1
+    }
+}
+
+/// Parses `field`, a slice of `line`, reporting where it starts on failure.
+pub fn parse_field<T>(line: &str, field: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    T::from_str(field)
+        .map_err(|e| Error::bad_input(format!("{:?}: {}", field, e)).column(column(line, field)))
+}
+
+/// Parses each of `lines` with `parse`, adding the line number to any error.
+pub fn parse_lines<T>(
+    lines: &[String],
+    mut parse: impl FnMut(&str) -> Result<T>,
+) -> Result<Vec<T>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(n, line)| parse(line).map_err(|e| e.line(n + 1)))
+        .collect()
+}
+
+/// Prints `result`'s error, if any, and exits with the matching code.
+pub fn exit(result: Result<()>) {
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        process::exit(error.exit_code());
+    }
+}
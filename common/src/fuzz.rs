@@ -0,0 +1,252 @@
+//! Feeds arbitrary bytes to a parser to check that bad input only ever
+//! produces an error.
+//!
+//! Each input is parsed on its own thread, so a panic is caught and a
+//! parser that never returns is given up on after a timeout. A thread that
+//! timed out is left running, as there is no way to stop it.
+
+use std::fmt;
+use std::panic;
+use std::sync::mpsc;
+use std::sync::{Arc, Once};
+use std::thread;
+use std::time::Duration;
+
+use crate::{normalise, Rng};
+
+/// Something that parses lines, ignoring whether it succeeded.
+pub type Target = Arc<dyn Fn(&[String]) + Send + Sync>;
+
+/// The name of the threads inputs are parsed on, whose panics are not
+/// printed.
+const THREAD_NAME: &str = "fuzz";
+
+/// Byte strings that the parsers give meaning to, so that mutations reach
+/// further than random bytes would. The numbers sit at and just past the
+/// limits of the integer types the days parse into.
+const DICTIONARY: &[&[u8]] = &[
+    b"\n",
+    b"\n\n",
+    b" ",
+    b"\t",
+    b":",
+    b",",
+    b"-",
+    b"+",
+    b"=",
+    b"#",
+    b"(",
+    b")",
+    b"[",
+    b"]",
+    b"x",
+    b"0",
+    b"9",
+    b"-1",
+    b"2147483647",
+    b"4294967295",
+    b"4294967296",
+    b"9223372036854775807",
+    b"-9223372036854775808",
+    b"18446744073709551615",
+    b"18446744073709551616",
+    b"\xef\xbb\xbf",
+    b"\xff",
+];
+
+/// The longest input mutation makes. Splicing would otherwise let inputs
+/// double in length with each generation.
+const MAX_LEN: usize = 4096;
+
+/// How an input went wrong.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// Parsing panicked with the given message.
+    Panic(String),
+    /// Parsing was still going when the timeout ran out.
+    Timeout,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+            Failure::Timeout => write!(f, "timed out"),
+        }
+    }
+}
+
+/// An input that made the target fail.
+#[derive(Debug)]
+pub struct Crash {
+    pub input: Vec<u8>,
+    pub failure: Failure,
+}
+
+/// Splits raw bytes into lines the same way input files are read.
+pub fn lines(input: &[u8]) -> Vec<String> {
+    normalise(&String::from_utf8_lossy(input)).0
+}
+
+/// Keeps panics on fuzzing threads from being printed, while leaving
+/// every other thread's alone.
+fn quieten_panics() {
+    static QUIETEN: Once = Once::new();
+
+    QUIETEN.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(THREAD_NAME) {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Parses `input` with `target`, returning how it failed if it did.
+pub fn run(target: &Target, input: &[u8], timeout: Duration) -> Option<Failure> {
+    quieten_panics();
+
+    let (sender, receiver) = mpsc::channel();
+    let target = Arc::clone(target);
+    let lines = lines(input);
+
+    thread::Builder::new()
+        .name(String::from(THREAD_NAME))
+        .spawn(move || {
+            let result = panic::catch_unwind(panic::AssertUnwindSafe(|| target(&lines)));
+            let _ = sender.send(result);
+        })
+        .expect("failed to start a fuzzing thread");
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(())) => None,
+        Ok(Err(payload)) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| String::from("<unknown>"));
+            Some(Failure::Panic(message))
+        }
+        Err(_) => Some(Failure::Timeout),
+    }
+}
+
+/// Makes a new input from `input` with a few random edits, sometimes
+/// splicing in part of another input from `corpus`.
+pub fn mutate(rng: &mut Rng, input: &[u8], corpus: &[Vec<u8>]) -> Vec<u8> {
+    let mut input = input.to_vec();
+
+    for _ in 0..rng.range(1..5) {
+        let at = rng.index(input.len() + 1);
+        match rng.index(6) {
+            0 if at < input.len() => input[at] = rng.next_u64() as u8,
+            1 => input.insert(at, rng.next_u64() as u8),
+            2 if at < input.len() => {
+                let end = at + rng.index((input.len() - at).min(16)) + 1;
+                input.drain(at..end);
+            }
+            3 if at < input.len() => {
+                let end = at + rng.index((input.len() - at).min(64)) + 1;
+                let copy = input[at..end].to_vec();
+                let to = rng.index(input.len() + 1);
+                input.splice(to..to, copy);
+            }
+            4 if !corpus.is_empty() => {
+                let other = rng.choose(corpus);
+                let start = rng.index(other.len() + 1);
+                let end = start + rng.index(other.len() - start + 1);
+                input.splice(at..at, other[start..end].iter().copied());
+            }
+            _ => {
+                let token = rng.choose(DICTIONARY);
+                input.splice(at..at, token.iter().copied());
+            }
+        }
+    }
+
+    input.truncate(MAX_LEN);
+    input
+}
+
+/// Shrinks a crashing input, first by whole lines and then by ever smaller
+/// runs of bytes, for as long as it keeps failing in the same way.
+///
+/// Inputs that time out are left as they are, since every attempt to
+/// shrink them would cost a whole timeout.
+pub fn minimise(target: &Target, crash: Crash, timeout: Duration) -> Crash {
+    let Crash { mut input, failure } = crash;
+    if failure == Failure::Timeout {
+        return Crash { input, failure };
+    }
+
+    let still_fails =
+        |candidate: &[u8]| matches!(run(target, candidate, timeout), Some(Failure::Panic(_)));
+
+    let mut i = 0;
+    while i < input.len() {
+        let end = input[i..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map_or(input.len(), |n| i + n + 1);
+        let candidate = [&input[..i], &input[end..]].concat();
+        if still_fails(&candidate) {
+            input = candidate;
+        } else {
+            i = end;
+        }
+    }
+
+    let mut chunk = input.len() / 2;
+    while chunk > 0 {
+        let mut i = 0;
+        while i + chunk <= input.len() {
+            let candidate = [&input[..i], &input[i + chunk..]].concat();
+            if still_fails(&candidate) {
+                input = candidate;
+            } else {
+                i += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+
+    let failure = run(target, &input, timeout).unwrap_or(failure);
+    Crash { input, failure }
+}
+
+/// Tries `runs` mutations of the `seeds` and returns the first input that
+/// made `target` fail, minimised.
+///
+/// Every input that did not fail joins the corpus, so later mutations
+/// build on earlier ones rather than always starting from the seeds.
+pub fn fuzz(
+    target: &Target,
+    seeds: &[Vec<u8>],
+    rng: &mut Rng,
+    runs: usize,
+    timeout: Duration,
+) -> Option<Crash> {
+    let mut corpus = seeds.to_vec();
+    if corpus.is_empty() {
+        corpus.push(Vec::new());
+    }
+
+    for _ in 0..runs {
+        let parent = rng.index(corpus.len());
+        let input = mutate(rng, &corpus[parent], &corpus);
+
+        if let Some(failure) = run(target, &input, timeout) {
+            return Some(minimise(target, Crash { input, failure }, timeout));
+        }
+
+        if corpus.len() < 256 {
+            corpus.push(input);
+        } else {
+            corpus[parent] = input;
+        }
+    }
+
+    None
+}
@@ -0,0 +1,75 @@
+use std::ops::Range;
+
+use crate::Answers;
+
+/// A small deterministic random number generator, so that a seed always
+/// produces the same input on every platform.
+///
+/// This is SplitMix64, which is plenty for making puzzle inputs and is not
+/// suitable for anything that needs to be unpredictable.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "empty range {:?}", range);
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    /// Returns an index below `n`, which must be positive.
+    pub fn index(&mut self, n: usize) -> usize {
+        self.range(0..n as u64) as usize
+    }
+
+    /// Returns true with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(0..denominator) < numerator
+    }
+
+    /// Picks an item from `items`, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles `items` in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// A generated input along with any answers planted in it.
+#[derive(Debug, Default)]
+pub struct Generated {
+    pub lines: Vec<String>,
+    /// The answers the input was built to have. A part is `None` when the
+    /// generator cannot know its answer without solving the puzzle.
+    pub answers: Answers,
+}
+
+/// Makes valid inputs for a day's puzzle.
+///
+/// The same seed and size always give the same input. What the size
+/// measures depends on the day, but it is roughly the number of lines.
+pub trait Generator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated;
+
+    /// A size close to that of a real input.
+    fn default_size(&self) -> usize;
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Result};
+
+/// The four orthogonal steps, as `(dx, dy)`.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The eight orthogonal and diagonal steps, as `(dx, dy)`.
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row, addressed as `(x, y)` from the
+/// top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+
+        if let Some(n) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::bad_input(format!(
+                "expected {} cells, got {}",
+                width,
+                rows[n].len()
+            ))
+            .line(n + 1));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, converting each character with `cell`.
+    ///
+    /// The map must have at least one cell, and a character that `cell`
+    /// rejects is reported with its line and column.
+    pub fn parse(lines: &[String], mut cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let rows = crate::parse_lines(lines, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    cell(c).ok_or_else(|| {
+                        Error::bad_input(format!("unexpected {:?}", c)).column(i + 1)
+                    })
+                })
+                .collect()
+        })?;
+
+        let grid = Self::from_rows(rows)?;
+        if grid.cells.is_empty() {
+            return Err(Error::bad_input("the map is empty"));
+        }
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at `(x, y)` with both coordinates wrapped around
+    /// the edges, so the grid repeats in every direction.
+    pub fn get_wrapped(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    /// Steps from `(x, y)` by `(dx, dy)`, if that stays on the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates over every cell along with its position.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Builds a grid of the same shape from each cell and its position.
+    pub fn map<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.positions().map(|(at, cell)| f(at, cell)).collect(),
+        }
+    }
+
+    /// The positions next to `at` in the given directions that are on the
+    /// grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        at: (usize, usize),
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        directions
+            .iter()
+            .filter_map(move |direction| self.step(at, *direction))
+    }
+
+    /// The up to four orthogonal neighbours of `at`.
+    pub fn neighbours4(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(at, &DIRECTIONS4)
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `at`.
+    pub fn neighbours8(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(at, &DIRECTIONS8)
+    }
+
+    /// Casts a ray from `at` in `direction`, yielding each cell it passes
+    /// until it leaves the grid. The starting cell is not included.
+    pub fn ray(
+        &self,
+        at: (usize, usize),
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let mut at = Some(at);
+        std::iter::from_fn(move || {
+            if direction == (0, 0) {
+                return None;
+            }
+            at = self.step(at?, direction);
+            at.map(|at| (at, &self[at]))
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{self, BufReader};
+use std::str::FromStr;
+
+use clap::{App, Arg, ArgMatches};
+
+mod answer;
+mod bench;
+pub mod differential;
+pub mod docs;
+mod error;
+pub mod fuzz;
+mod generate;
+mod grid;
+mod log;
+mod normalise;
+pub mod number_theory;
+mod part;
+pub mod plugin;
+mod records;
+mod report;
+mod solution;
+
+pub use serde_json;
+
+pub use crate::answer::{Answer, Frame};
+pub use crate::bench::{bench, Stats, Timings};
+pub use crate::error::{column, exit, parse_field, parse_lines, Error, ErrorKind, Result};
+pub use crate::generate::{Generated, Generator, Rng};
+pub use crate::grid::{Grid, DIRECTIONS4, DIRECTIONS8};
+pub use crate::log::{
+    current_verbosity, enabled, hold_log, release_log, set_verbosity, write_log, Verbosity,
+};
+pub use crate::normalise::{normalise, Changes};
+pub use crate::part::Part;
+pub use crate::records::{Record, Records};
+pub use crate::report::{report, Report};
+pub use crate::solution::{solve, Answers, Solution, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Json,
+}
+
+/// Creates the `App` for a given day with the standard title, the
+/// verbosity flags and the `completions` and `man` subcommands.
+pub fn app(day: u32) -> App<'static, 'static> {
+    let app = App::new(format!("AOC2020 Day {}", day))
+        .bin_name(format!("day{}", day))
+        .arg(quiet_arg())
+        .arg(verbose_arg());
+
+    docs::with_subcommands(app)
+}
+
+/// The positional `INPUT` argument shared by every day.
+pub fn input_arg() -> Arg<'static, 'static> {
+    Arg::with_name("INPUT")
+        .help("Input file name, or - for standard input")
+        .required(true)
+        .index(1)
+}
+
+/// The optional `--part` argument for running a single part.
+pub fn part_arg() -> Arg<'static, 'static> {
+    Arg::with_name("PART")
+        .long("part")
+        .help("Only run the given part")
+        .takes_value(true)
+        .possible_values(&["1", "2"])
+}
+
+/// Returns the part selected by the `--part` argument.
+pub fn part(matches: &ArgMatches) -> Part {
+    match matches.value_of("PART") {
+        Some("1") => Part::One,
+        Some("2") => Part::Two,
+        _ => Part::Both,
+    }
+}
+
+/// The optional `--bench` argument for timing repeated runs.
+pub fn bench_arg() -> Arg<'static, 'static> {
+    Arg::with_name("BENCH")
+        .long("bench")
+        .value_name("N")
+        .help("Time parsing and each part over N runs")
+        .takes_value(true)
+        .validator(|v| match usize::from_str(&v) {
+            Ok(runs) if runs > 0 => Ok(()),
+            _ => Err(String::from(
+                "the number of runs must be a positive integer",
+            )),
+        })
+}
+
+/// Returns the number of runs requested by the `--bench` argument.
+pub fn bench_runs(matches: &ArgMatches) -> Option<usize> {
+    matches
+        .value_of("BENCH")
+        .map(|v| usize::from_str(v).unwrap())
+}
+
+/// The optional `--format` argument for choosing the output format.
+pub fn format_arg() -> Arg<'static, 'static> {
+    Arg::with_name("FORMAT")
+        .long("format")
+        .help("Output format")
+        .takes_value(true)
+        .possible_values(&["human", "json"])
+        .default_value("human")
+}
+
+/// Returns the format selected by the `--format` argument.
+pub fn format(matches: &ArgMatches) -> Format {
+    match matches.value_of("FORMAT") {
+        Some("json") => Format::Json,
+        _ => Format::Human,
+    }
+}
+
+/// The `-q`/`--quiet` flag for silencing everything but answers and errors.
+pub fn quiet_arg() -> Arg<'static, 'static> {
+    Arg::with_name("QUIET")
+        .short("q")
+        .long("quiet")
+        .help("Only print answers and errors")
+        .conflicts_with("VERBOSE")
+}
+
+/// The `-v`/`--verbose` flag, which can be repeated for more detail.
+pub fn verbose_arg() -> Arg<'static, 'static> {
+    Arg::with_name("VERBOSE")
+        .short("v")
+        .long("verbose")
+        .help("Explain the working; repeat to trace every step")
+        .multiple(true)
+}
+
+/// Returns the verbosity selected by the `-q` and `-v` flags.
+pub fn verbosity(matches: &ArgMatches) -> Verbosity {
+    if matches.is_present("QUIET") {
+        return Verbosity::Quiet;
+    }
+
+    match matches.occurrences_of("VERBOSE") {
+        0 => Verbosity::Normal,
+        1 => Verbosity::Verbose,
+        _ => Verbosity::Trace,
+    }
+}
+
+/// Runs `solution` on `lines` in the way the shared arguments ask for.
+///
+/// `human` prints the day's own output when neither benchmarking nor JSON
+/// output was requested.
+pub fn dispatch<S: Solution>(
+    day: u32,
+    solution: &S,
+    matches: &ArgMatches,
+    lines: &[String],
+    human: impl FnOnce(&[String], Part) -> Result<()>,
+) -> Result<()> {
+    let part = part(matches);
+    let runs = bench_runs(matches);
+
+    let result = match format(matches) {
+        Format::Json => report(solution, day, lines, part, runs.unwrap_or(1))
+            .map(|report| println!("{}", report.to_json())),
+        Format::Human => match runs {
+            Some(runs) => bench(solution, lines, part, runs).map(|timings| print!("{}", timings)),
+            None => human(lines, part),
+        },
+    };
+
+    match matches.value_of("INPUT") {
+        Some(path) => result.map_err(|e| e.file(path)),
+        None => result,
+    }
+}
+
+/// Opens `path` for reading, treating `-` as standard input.
+pub fn open(path: &str) -> io::Result<Box<dyn BufRead>> {
+    if path == "-" {
+        Ok(Box::new(BufReader::new(io::stdin())))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+/// Reads every line of `path`, treating `-` as standard input.
+///
+/// The input is passed through [`normalise`], and anything it had to
+/// change is reported at the verbose level.
+pub fn read_lines_from(path: &str) -> Result<Vec<String>> {
+    let mut text = String::new();
+    open(path)
+        .and_then(|mut input| input.read_to_string(&mut text))
+        .map_err(|e| Error::from(e).file(path))?;
+
+    let (lines, changes) = normalise(&text);
+    if !changes.is_empty() {
+        debug!("{}: {}", display_path(path), changes);
+    }
+
+    Ok(lines)
+}
+
+/// The name to report for `path`, which is `<stdin>` for `-`.
+pub(crate) fn display_path(path: &str) -> &str {
+    if path == "-" {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+/// Reads every line of the file named by the `INPUT` argument.
+pub fn read_lines(matches: &ArgMatches) -> Result<Vec<String>> {
+    read_lines_from(matches.value_of("INPUT").unwrap())
+}
+
+/// Groups lines into records separated by blank lines.
+pub fn records(lines: &[String]) -> Records<'_> {
+    Records::new(lines)
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// How much is written to standard error besides answers and errors.
+///
+/// Log messages never go to standard output, so they cannot get mixed up
+/// with answers or JSON.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Nothing at all.
+    Quiet,
+    /// Notes the user should see, such as a missing input.
+    Normal,
+    /// What was done to the input and how the answers were reached.
+    Verbose,
+    /// Every step of working.
+    Trace,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn current_verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        1 => Verbosity::Normal,
+        2 => Verbosity::Verbose,
+        _ => Verbosity::Trace,
+    }
+}
+
+/// Whether messages at `verbosity` are being written.
+pub fn enabled(verbosity: Verbosity) -> bool {
+    current_verbosity() >= verbosity
+}
+
+/// Messages held back from standard error while something else, such as
+/// the dashboard, has the terminal.
+static HELD: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+/// Holds back log messages instead of writing them, until `release_log`.
+pub fn hold_log() {
+    HELD.lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert_with(Vec::new);
+}
+
+/// Goes back to writing log messages, and returns those held back.
+pub fn release_log() -> Vec<String> {
+    HELD.lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+        .unwrap_or_default()
+}
+
+/// Writes a message to standard error, or holds it back.
+pub fn write_log(message: fmt::Arguments<'_>) {
+    match HELD.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        Some(held) => held.push(message.to_string()),
+        None => eprintln!("{}", message),
+    }
+}
+
+/// Writes a message to standard error if `$verbosity` is enabled.
+#[macro_export]
+macro_rules! log {
+    ($verbosity:expr, ) => {
+        $crate::log!($verbosity, "")
+    };
+    ($verbosity:expr, $($arg:tt)*) => {
+        if $crate::enabled($verbosity) {
+            $crate::write_log(format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::Verbosity::Normal, $($arg)*)
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::Verbosity::Verbose, $($arg)*)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::Verbosity::Trace, $($arg)*)
+    };
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+const BOM: char = '\u{feff}';
+
+/// What [`normalise`] had to change to tidy up an input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    /// Whether a UTF-8 byte-order mark was removed.
+    pub bom: bool,
+    /// The number of CRLF line endings converted.
+    pub crlf: usize,
+    /// The number of bare CR line endings converted.
+    pub cr: usize,
+    /// The number of lines that had trailing whitespace removed.
+    pub trailing_whitespace: usize,
+    /// The number of blank lines dropped from the end of the input.
+    pub trailing_blank_lines: usize,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn plural(n: usize, one: &str, many: &str) -> String {
+    format!("{} {}", n, if n == 1 { one } else { many })
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut changes = Vec::new();
+
+        if self.bom {
+            changes.push(String::from("removed a byte-order mark"));
+        }
+        if self.crlf > 0 {
+            changes.push(format!(
+                "converted {}",
+                plural(self.crlf, "CRLF line ending", "CRLF line endings")
+            ));
+        }
+        if self.cr > 0 {
+            changes.push(format!(
+                "converted {}",
+                plural(self.cr, "CR line ending", "CR line endings")
+            ));
+        }
+        if self.trailing_whitespace > 0 {
+            changes.push(format!(
+                "trimmed trailing whitespace from {}",
+                plural(self.trailing_whitespace, "line", "lines")
+            ));
+        }
+        if self.trailing_blank_lines > 0 {
+            changes.push(format!(
+                "dropped {}",
+                plural(
+                    self.trailing_blank_lines,
+                    "trailing blank line",
+                    "trailing blank lines"
+                )
+            ));
+        }
+
+        if changes.is_empty() {
+            write!(f, "no changes")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
+/// Splits `text` into lines, tidying up the things that tend to creep into
+/// inputs copied from a browser.
+///
+/// CRLF and bare CR line endings are treated like LF, a leading byte-order
+/// mark is removed, trailing whitespace is trimmed from every line and
+/// blank lines at the end are dropped. Blank lines elsewhere are kept, as
+/// some days use them to separate records.
+pub fn normalise(text: &str) -> (Vec<String>, Changes) {
+    let mut changes = Changes::default();
+
+    let text = match text.strip_prefix(BOM) {
+        Some(text) => {
+            changes.bom = true;
+            text
+        }
+        None => text,
+    };
+
+    let mut lines = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let end = rest.find(['\r', '\n']).unwrap_or(rest.len());
+        lines.push(&rest[..end]);
+
+        rest = &rest[end..];
+        if let Some(next) = rest.strip_prefix("\r\n") {
+            changes.crlf += 1;
+            rest = next;
+        } else if let Some(next) = rest.strip_prefix('\r') {
+            changes.cr += 1;
+            rest = next;
+        } else if let Some(next) = rest.strip_prefix('\n') {
+            rest = next;
+        }
+    }
+
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|line| {
+            let trimmed = line.trim_end();
+            if trimmed.len() != line.len() {
+                changes.trailing_whitespace += 1;
+            }
+            String::from(trimmed)
+        })
+        .collect();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+        changes.trailing_blank_lines += 1;
+    }
+
+    (lines, changes)
+}
@@ -0,0 +1,109 @@
+//! Modular arithmetic helpers.
+//!
+//! Intermediate values are held as `i128` so that products of two `u64`
+//! moduli never overflow.
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g`, where `g` is the
+/// non-negative greatest common divisor of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    extended_gcd(a.into(), b.into()).0 as u64
+}
+
+/// Returns the least common multiple of `a` and `b`, or `None` if it does
+/// not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Returns the inverse of `a` modulo `m` in `0..m`, or `None` if `a` and
+/// `m` are not coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// Returns `base` raised to `exponent`, modulo `m`.
+pub fn mod_pow(base: u64, exponent: u64, m: u64) -> u64 {
+    assert!(m > 0, "modulus must be positive");
+
+    let m = u128::from(m);
+    let mut base = u128::from(base) % m;
+    let mut exponent = exponent;
+    let mut result = 1 % m;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exponent >>= 1;
+    }
+
+    result as u64
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`.
+///
+/// Returns the smallest non-negative `x` and the modulus it is unique to,
+/// which is the lcm of the moduli. Moduli need not be coprime. Returns
+/// `None` if the congruences contradict each other, a modulus is zero, or
+/// the combined modulus does not fit in a `u64`.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let mut x: i128 = 0;
+    let mut m: i128 = 1;
+
+    for (residue, modulus) in congruences.iter().copied() {
+        if modulus == 0 {
+            return None;
+        }
+
+        let (residue, modulus) = (i128::from(residue), i128::from(modulus));
+        let (g, p, _) = extended_gcd(m, modulus);
+        let difference = residue - x;
+        if difference.rem_euclid(g) != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let combined = m.checked_mul(step).filter(|c| *c <= i128::from(u64::MAX))?;
+
+        // Every factor here is below 2^64, so the products fit in a u128.
+        let k =
+            (difference / g).rem_euclid(step) as u128 * p.rem_euclid(step) as u128 % step as u128;
+        x = ((x as u128 + m as u128 * k) % combined as u128) as i128;
+        m = combined;
+    }
+
+    Some((x as u64, m as u64))
+}
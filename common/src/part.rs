@@ -0,0 +1,16 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn one(self) -> bool {
+        self != Self::Two
+    }
+
+    pub fn two(self) -> bool {
+        self != Self::One
+    }
+}
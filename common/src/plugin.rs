@@ -0,0 +1,252 @@
+//! The interface between the runner and day solvers loaded from shared
+//! libraries.
+//!
+//! A plugin exports [`ENTRY_POINT`], a [`RegisterFn`] that the runner calls
+//! with the ABI version it speaks. The plugin answers with its
+//! [`Registration`], or null if it cannot speak that version. Only C types
+//! cross the boundary, so a plugin need not be built with the same compiler
+//! as the runner, or in Rust at all.
+//!
+//! A plugin written against this crate can use [`export_plugin!`] to do
+//! all of that for a [`Solution`].
+//!
+//! [`export_plugin!`]: crate::export_plugin
+
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use crate::{normalise, Error, ErrorKind, Part, Result, Solution};
+
+/// The version of this interface. It changes whenever `Registration` or
+/// `SolveFn` do.
+pub const ABI_VERSION: u32 = 1;
+
+/// The name of the symbol every plugin exports.
+pub const ENTRY_POINT: &str = "aoc_plugin_register";
+
+/// The bit in `Registration::parts` for part 1.
+pub const PART_ONE: u32 = 1;
+/// The bit in `Registration::parts` for part 2.
+pub const PART_TWO: u32 = 2;
+
+/// A `SolveFn` succeeded. Otherwise it returns one of the codes below or
+/// the exit code of an [`ErrorKind`](crate::ErrorKind).
+pub const OK: c_int = 0;
+/// The part asked for is not one the plugin solves.
+pub const ERR_USAGE: c_int = 64;
+/// The solver panicked.
+pub const ERR_PANIC: c_int = 70;
+/// The answer does not fit in the buffer.
+pub const ERR_BUFFER: c_int = 71;
+
+/// Where in the input an error was found. Zero means not known.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Solves one part for `len` bytes of UTF-8 input.
+///
+/// The answer, or on failure a message, is written to `out_buf` as a
+/// NUL-terminated string, a message being cut short to fit. On failure
+/// `position` may also be filled in.
+pub type SolveFn = unsafe extern "C" fn(
+    part: u32,
+    input: *const u8,
+    len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+    position: *mut Position,
+) -> c_int;
+
+/// The entry point, called with the runner's `ABI_VERSION`.
+pub type RegisterFn = unsafe extern "C" fn(abi_version: u32) -> *const Registration;
+
+/// What a plugin solves and how to call it.
+#[repr(C)]
+pub struct Registration {
+    /// The `ABI_VERSION` the plugin was built against.
+    pub abi_version: u32,
+    pub day: u32,
+    /// `PART_ONE`, `PART_TWO` or both.
+    pub parts: u32,
+    /// A NUL-terminated name that tells the plugin apart from others for
+    /// the same day.
+    pub name: *const c_char,
+    /// A NUL-terminated description of the input the plugin expects.
+    pub input_format: *const c_char,
+    pub solve: SolveFn,
+}
+
+// A registration only ever points at static strings.
+unsafe impl Sync for Registration {}
+
+fn answer<S: Solution>(solution: &S, part: u32, input: &[u8]) -> Result<String> {
+    let input =
+        std::str::from_utf8(input).map_err(|_| Error::bad_input("the input is not UTF-8"))?;
+    let input = solution.parse(&normalise(input).0)?;
+
+    Ok(match part {
+        1 => solution.part1(&input)?.to_string(),
+        _ => solution.part2(&input)?.to_string(),
+    })
+}
+
+/// Copies as much of `text` as fits into `out`, followed by a NUL, and
+/// says whether all of it did.
+fn write(out: &mut [u8], text: &str) -> bool {
+    let room = match out.len().checked_sub(1) {
+        Some(room) => room,
+        None => return text.is_empty(),
+    };
+
+    let mut end = text.len().min(room);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    out[..end].copy_from_slice(&text.as_bytes()[..end]);
+    out[end] = 0;
+    end == text.len()
+}
+
+/// Carries out a `SolveFn` call with `solution`.
+///
+/// # Safety
+///
+/// The pointers must be as described for `SolveFn`: `input` points to
+/// `len` readable bytes, `out_buf` to `out_len` writable bytes and
+/// `position` to a `Position`, or is null.
+pub unsafe fn solve<S: Solution>(
+    solution: &S,
+    part: u32,
+    input: *const u8,
+    len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+    position: *mut Position,
+) -> c_int {
+    let out = if out_buf.is_null() {
+        &mut [][..]
+    } else {
+        slice::from_raw_parts_mut(out_buf as *mut u8, out_len)
+    };
+    let input = if input.is_null() {
+        &[][..]
+    } else {
+        slice::from_raw_parts(input, len)
+    };
+
+    if part != 1 && part != 2 {
+        write(out, &format!("there is no part {}", part));
+        return ERR_USAGE;
+    }
+
+    match panic::catch_unwind(AssertUnwindSafe(|| answer(solution, part, input))) {
+        Ok(Ok(answer)) if write(out, &answer) => OK,
+        Ok(Ok(answer)) => {
+            write(out, &format!("the answer needs {} bytes", answer.len() + 1));
+            ERR_BUFFER
+        }
+        Ok(Err(error)) => {
+            if let Some(position) = position.as_mut() {
+                position.line = error.line.unwrap_or(0);
+                position.column = error.column.unwrap_or(0);
+            }
+            write(out, &error.cause);
+            error.exit_code()
+        }
+        Err(_) => {
+            write(out, "the solver panicked");
+            ERR_PANIC
+        }
+    }
+}
+
+/// Turns what a `SolveFn` returned back into an error.
+pub fn error(code: c_int, message: &str, position: Position) -> Error {
+    let mut error = match code {
+        ERR_USAGE => Error::config(message),
+        ERR_PANIC => Error::internal(format!("the plugin panicked: {}", message)),
+        ERR_BUFFER => Error::internal(format!("the answer is too long: {}", message)),
+        code => match ErrorKind::from_exit_code(code) {
+            Some(kind) => Error::new(kind, message),
+            None => Error::internal(format!("plugin failed with code {}: {}", code, message)),
+        },
+    };
+
+    if position.line > 0 {
+        error = error.line(position.line);
+    }
+    if position.column > 0 {
+        error = error.column(position.column);
+    }
+    error
+}
+
+/// Narrows `requested` to the parts in a registration's `bits` when both
+/// were asked for.
+pub fn parts(bits: u32, requested: Part) -> Part {
+    match (bits & PART_ONE != 0, bits & PART_TWO != 0, requested) {
+        (true, false, Part::Both) => Part::One,
+        (false, true, Part::Both) => Part::Two,
+        _ => requested,
+    }
+}
+
+/// Exports a `Solution` as a plugin for `day`.
+///
+/// ```ignore
+/// aoc_common::export_plugin! {
+///     day: 1,
+///     name: "sorted",
+///     parts: aoc_common::plugin::PART_ONE | aoc_common::plugin::PART_TWO,
+///     input: "one number per line",
+///     solution: Sorted,
+/// }
+/// ```
+#[macro_export]
+macro_rules! export_plugin {
+    (
+        day: $day:expr,
+        name: $name:literal,
+        parts: $parts:expr,
+        input: $format:literal,
+        solution: $solution:expr $(,)?
+    ) => {
+        unsafe extern "C" fn aoc_plugin_solve(
+            part: u32,
+            input: *const u8,
+            len: usize,
+            out_buf: *mut ::std::os::raw::c_char,
+            out_len: usize,
+            position: *mut $crate::plugin::Position,
+        ) -> ::std::os::raw::c_int {
+            $crate::plugin::solve(&$solution, part, input, len, out_buf, out_len, position)
+        }
+
+        static AOC_PLUGIN_REGISTRATION: $crate::plugin::Registration =
+            $crate::plugin::Registration {
+                abi_version: $crate::plugin::ABI_VERSION,
+                day: $day,
+                parts: $parts,
+                name: concat!($name, "\0").as_ptr() as *const ::std::os::raw::c_char,
+                input_format: concat!($format, "\0").as_ptr() as *const ::std::os::raw::c_char,
+                solve: aoc_plugin_solve,
+            };
+
+        /// The plugin's entry point.
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_register(
+            abi_version: u32,
+        ) -> *const $crate::plugin::Registration {
+            if abi_version == $crate::plugin::ABI_VERSION {
+                &AOC_PLUGIN_REGISTRATION
+            } else {
+                ::std::ptr::null()
+            }
+        }
+    };
+}
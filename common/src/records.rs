@@ -0,0 +1,65 @@
+use std::ops::RangeInclusive;
+
+/// A run of consecutive non-blank lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub lines: &'a [String],
+    /// The line number of the record's first line, starting at 1.
+    pub first_line: usize,
+}
+
+impl<'a> Record<'a> {
+    /// The line numbers covered by the record.
+    pub fn span(&self) -> RangeInclusive<usize> {
+        self.first_line..=self.first_line + self.lines.len() - 1
+    }
+
+    /// Iterates over the record's lines along with their line numbers.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &'a String)> {
+        let first_line = self.first_line;
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (first_line + i, line))
+    }
+}
+
+/// Splits lines into records separated by blank lines.
+///
+/// Leading, trailing and repeated blank lines never produce empty records,
+/// and a line holding only whitespace counts as blank.
+pub struct Records<'a> {
+    lines: &'a [String],
+    next: usize,
+}
+
+impl<'a> Records<'a> {
+    pub fn new(lines: &'a [String]) -> Self {
+        Self { lines, next: 0 }
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.lines[self.next..];
+        let start = self.next + rest.iter().position(|line| !is_blank(line))?;
+
+        let rest = &self.lines[start..];
+        let len = rest
+            .iter()
+            .position(|line| is_blank(line))
+            .unwrap_or(rest.len());
+        self.next = start + len;
+
+        Some(Record {
+            lines: &rest[..len],
+            first_line: start + 1,
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use serde_json::{json, Map, Value};
+
+use crate::bench::{time, Stats, Timings};
+use crate::{Answer, Answers, Part, Result, Solution};
+
+pub struct Report {
+    pub day: u32,
+    pub answers: Answers,
+    pub diagnostics: Map<String, Value>,
+    /// Drawings from the answers that have one, keyed by part.
+    pub visualisations: Map<String, Value>,
+    pub timings: Timings,
+}
+
+impl Report {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "part1": self.answers.part1,
+            "part2": self.answers.part2,
+            "timings": self.timings.to_json(),
+            "diagnostics": self.diagnostics,
+            "visualisations": self.visualisations,
+        })
+    }
+}
+
+/// The answers and what they have to say for themselves, from the first
+/// run.
+#[derive(Default)]
+struct Recorded {
+    answers: Answers,
+    diagnostics: Map<String, Value>,
+    visualisations: Map<String, Value>,
+}
+
+impl Recorded {
+    fn record<A: Answer>(&mut self, answer: A, part: Part) {
+        let (name, slot) = match part {
+            Part::Two => ("part2", &mut self.answers.part2),
+            _ => ("part1", &mut self.answers.part1),
+        };
+
+        *slot = Some(answer.to_string());
+        if let Some(value) = answer.diagnostics() {
+            self.diagnostics.insert(name.to_string(), value);
+        }
+        if let Some(drawing) = answer.visualisation() {
+            self.visualisations
+                .insert(name.to_string(), Value::String(drawing));
+        }
+    }
+}
+
+/// Solves the selected parts `runs` times, keeping the first run's answers.
+pub fn report<S: Solution>(
+    solution: &S,
+    day: u32,
+    lines: &[String],
+    part: Part,
+    runs: usize,
+) -> Result<Report> {
+    let mut recorded = Recorded::default();
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for run in 0..runs {
+        let input = time(&mut parse, || solution.parse(lines))?;
+
+        if part.one() {
+            let answer = time(&mut part1, || solution.part1(&input))?;
+            if run == 0 {
+                recorded.record(answer, Part::One);
+            }
+        }
+
+        if part.two() {
+            let answer = time(&mut part2, || solution.part2(&input))?;
+            if run == 0 {
+                recorded.record(answer, Part::Two);
+            }
+        }
+    }
+
+    Ok(Report {
+        day,
+        answers: recorded.answers,
+        diagnostics: recorded.diagnostics,
+        visualisations: recorded.visualisations,
+        timings: Timings {
+            runs,
+            parse: Stats::from_samples(parse),
+            part1: Stats::from_optional_samples(part1),
+            part2: Stats::from_optional_samples(part2),
+        },
+    })
+}
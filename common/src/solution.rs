@@ -0,0 +1,65 @@
+use crate::{bench, report, Answer, Part, Report, Result, Timings};
+
+/// A day's puzzle, split into parsing and the two parts.
+///
+/// Answers display as the value that would be submitted for that part.
+/// Parsing fails with a bad-input error, and a part fails with a
+/// no-solution error when the input admits no answer.
+pub trait Solution {
+    type Input;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2>;
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Runs the selected parts of `solution`, keeping the displayed answers.
+pub fn solve<S: Solution>(solution: &S, lines: &[String], part: Part) -> Result<Answers> {
+    let input = solution.parse(lines)?;
+    let mut answers = Answers::default();
+
+    if part.one() {
+        answers.part1 = Some(solution.part1(&input)?.to_string());
+    }
+
+    if part.two() {
+        answers.part2 = Some(solution.part2(&input)?.to_string());
+    }
+
+    Ok(answers)
+}
+
+/// An object-safe view of a `Solution`, for choosing a day at runtime.
+pub trait Solver {
+    /// Parses `lines` without solving either part.
+    fn parse_only(&self, lines: &[String]) -> Result<()>;
+    fn solve(&self, lines: &[String], part: Part) -> Result<Answers>;
+    fn bench(&self, lines: &[String], part: Part, runs: usize) -> Result<Timings>;
+    fn report(&self, day: u32, lines: &[String], part: Part, runs: usize) -> Result<Report>;
+}
+
+impl<S: Solution> Solver for S {
+    fn parse_only(&self, lines: &[String]) -> Result<()> {
+        self.parse(lines).map(|_| ())
+    }
+
+    fn solve(&self, lines: &[String], part: Part) -> Result<Answers> {
+        solve(self, lines, part)
+    }
+
+    fn bench(&self, lines: &[String], part: Part, runs: usize) -> Result<Timings> {
+        bench(self, lines, part, runs)
+    }
+
+    fn report(&self, day: u32, lines: &[String], part: Part, runs: usize) -> Result<Report> {
+        report(self, day, lines, part, runs)
+    }
+}
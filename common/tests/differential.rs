@@ -0,0 +1,72 @@
+use aoc_common::differential::{removals, Check, Differential};
+use aoc_common::Rng;
+
+/// A sum that goes wrong whenever a seven is involved.
+struct BrokenSum;
+
+impl Differential for BrokenSum {
+    const NAME: &'static str = "broken sum";
+    const LEFT: &'static str = "sum";
+    const RIGHT: &'static str = "broken";
+
+    type Input = Vec<u64>;
+    type Output = u64;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Input {
+        (0..size).map(|_| rng.range(0..10)).collect()
+    }
+
+    fn shrink(&self, numbers: &Self::Input) -> Vec<Self::Input> {
+        removals(numbers)
+    }
+
+    fn left(&self, numbers: &Self::Input) -> Self::Output {
+        numbers.iter().sum()
+    }
+
+    fn right(&self, numbers: &Self::Input) -> Self::Output {
+        numbers.iter().filter(|n| **n != 7).sum()
+    }
+}
+
+#[test]
+fn disagreements_shrink_to_the_smallest_input() {
+    let disagreement = BrokenSum.check(0..100, 20).unwrap();
+
+    assert_eq!(disagreement.name, "broken sum");
+    assert_eq!(disagreement.input, "[7]");
+    assert_eq!(disagreement.left, ("sum", String::from("7")));
+    assert_eq!(disagreement.right, ("broken", String::from("0")));
+}
+
+#[test]
+fn agreeing_sides_report_nothing() {
+    struct Sum;
+
+    impl Differential for Sum {
+        const NAME: &'static str = "sum";
+        const LEFT: &'static str = "forwards";
+        const RIGHT: &'static str = "backwards";
+
+        type Input = Vec<u64>;
+        type Output = u64;
+
+        fn generate(&self, rng: &mut Rng, size: usize) -> Self::Input {
+            (0..size).map(|_| rng.range(0..10)).collect()
+        }
+
+        fn shrink(&self, numbers: &Self::Input) -> Vec<Self::Input> {
+            removals(numbers)
+        }
+
+        fn left(&self, numbers: &Self::Input) -> Self::Output {
+            numbers.iter().sum()
+        }
+
+        fn right(&self, numbers: &Self::Input) -> Self::Output {
+            numbers.iter().rev().sum()
+        }
+    }
+
+    assert!(Sum.check(0..100, 20).is_none());
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use aoc_common::fuzz::{self, Failure, Target};
+use aoc_common::Rng;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Panics on any line containing a `!`.
+fn fragile() -> Target {
+    Arc::new(|lines: &[String]| {
+        if lines.iter().any(|line| line.contains('!')) {
+            panic!("found a !");
+        }
+    })
+}
+
+#[test]
+fn panics_are_caught_and_reported() {
+    assert_eq!(fuzz::run(&fragile(), b"fine\n", TIMEOUT), None);
+    assert_eq!(
+        fuzz::run(&fragile(), b"fine\nnot fine!\n", TIMEOUT),
+        Some(Failure::Panic(String::from("found a !")))
+    );
+}
+
+#[test]
+fn parsers_that_never_return_time_out() {
+    let target: Target = Arc::new(|_: &[String]| loop {
+        std::thread::park();
+    });
+
+    assert_eq!(fuzz::run(&target, b"", TIMEOUT), Some(Failure::Timeout));
+}
+
+#[test]
+fn crashes_are_minimised() {
+    let seeds = vec![b"one two three\nfour five six\n".to_vec()];
+    let crash = fuzz::fuzz(&fragile(), &seeds, &mut Rng::new(0), 10000, TIMEOUT).unwrap();
+
+    assert_eq!(crash.input, b"!");
+    assert_eq!(crash.failure, Failure::Panic(String::from("found a !")));
+}
+
+#[test]
+fn mutation_is_deterministic() {
+    let input = b"1721\n979\n366\n";
+    let mutate = || fuzz::mutate(&mut Rng::new(7), input, &[]);
+
+    assert_eq!(mutate(), mutate());
+}
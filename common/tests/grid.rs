@@ -0,0 +1,98 @@
+use aoc_common::{Grid, DIRECTIONS8};
+
+fn lines(map: &str) -> Vec<String> {
+    map.lines().map(String::from).collect()
+}
+
+fn digits(map: &str) -> Grid<u32> {
+    Grid::parse(&lines(map), |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn parses_char_maps() {
+    let grid = digits("123\n456\n");
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(0, 0)], 1);
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.to_string(), "123\n456\n");
+}
+
+#[test]
+fn rejects_bad_maps() {
+    let error = Grid::parse(&lines("123\n4x6\n"), |c| c.to_digit(10)).unwrap_err();
+    assert_eq!((error.line, error.column), (Some(2), Some(2)));
+
+    let error = Grid::parse(&lines("123\n45\n"), |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(error.line, Some(2));
+
+    assert!(Grid::parse(&[], |c| c.to_digit(10)).is_err());
+    assert!(Grid::parse(&lines("\n"), |c| c.to_digit(10)).is_err());
+}
+
+#[test]
+fn neighbours_stay_on_the_grid() {
+    let grid = digits("123\n456\n789\n");
+
+    let mut corner: Vec<u32> = grid.neighbours8((0, 0)).map(|at| grid[at]).collect();
+    corner.sort_unstable();
+    assert_eq!(corner, vec![2, 4, 5]);
+
+    let mut centre: Vec<u32> = grid.neighbours4((1, 1)).map(|at| grid[at]).collect();
+    centre.sort_unstable();
+    assert_eq!(centre, vec![2, 4, 6, 8]);
+
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+}
+
+#[test]
+fn neighbours_of_a_single_column() {
+    let grid = digits("1\n2\n3\n");
+
+    let mut middle: Vec<u32> = grid.neighbours8((0, 1)).map(|at| grid[at]).collect();
+    middle.sort_unstable();
+    assert_eq!(middle, vec![1, 3]);
+}
+
+#[test]
+fn rays_run_to_the_edge() {
+    let grid = digits("123\n456\n789\n");
+
+    let ray: Vec<u32> = grid.ray((0, 0), (1, 1)).map(|(_, v)| *v).collect();
+    assert_eq!(ray, vec![5, 9]);
+
+    let ray: Vec<u32> = grid.ray((2, 1), (-1, 0)).map(|(_, v)| *v).collect();
+    assert_eq!(ray, vec![5, 4]);
+
+    assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    assert_eq!(grid.ray((1, 1), (0, 0)).count(), 0);
+    assert_eq!(
+        DIRECTIONS8
+            .iter()
+            .map(|d| grid.ray((1, 1), *d).count())
+            .sum::<usize>(),
+        8
+    );
+}
+
+#[test]
+fn wraps_around() {
+    let grid = digits("12\n34\n");
+
+    assert_eq!(*grid.get_wrapped(2, 0), 1);
+    assert_eq!(*grid.get_wrapped(-1, 0), 2);
+    assert_eq!(*grid.get_wrapped(5, -1), 4);
+}
+
+#[test]
+fn maps_keep_their_shape() {
+    let grid = digits("12\n34\n");
+    let doubled = grid.map(|_, v| v * 2);
+
+    assert_eq!(doubled.to_string(), "24\n68\n");
+    assert_eq!(
+        grid.positions().map(|(at, _)| at).collect::<Vec<_>>(),
+        vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+    );
+}
@@ -0,0 +1,62 @@
+use aoc_common::{normalise, Changes};
+
+fn lines(text: &str) -> Vec<String> {
+    normalise(text).0
+}
+
+#[test]
+fn clean_input_is_unchanged() {
+    let (lines, changes) = normalise("a\n\nb\n");
+
+    assert_eq!(lines, vec!["a", "", "b"]);
+    assert!(changes.is_empty());
+    assert_eq!(changes.to_string(), "no changes");
+}
+
+#[test]
+fn line_endings_are_converted() {
+    let (lines, changes) = normalise("a\r\nb\rc\nd");
+
+    assert_eq!(lines, vec!["a", "b", "c", "d"]);
+    assert_eq!(changes.crlf, 1);
+    assert_eq!(changes.cr, 1);
+}
+
+#[test]
+fn byte_order_mark_is_removed() {
+    let (lines, changes) = normalise("\u{feff}1721\n979\n");
+
+    assert_eq!(lines, vec!["1721", "979"]);
+    assert!(changes.bom);
+}
+
+#[test]
+fn trailing_whitespace_and_blank_lines_are_dropped() {
+    let (lines, changes) = normalise("L.L \n\t\n.L\t\n\n  \n");
+
+    assert_eq!(lines, vec!["L.L", "", ".L"]);
+    assert_eq!(
+        changes,
+        Changes {
+            trailing_whitespace: 4,
+            trailing_blank_lines: 2,
+            ..Changes::default()
+        }
+    );
+}
+
+#[test]
+fn record_separators_are_kept() {
+    assert_eq!(lines("a\r\n\r\nb\r\n\r\n"), vec!["a", "", "b"]);
+}
+
+#[test]
+fn changes_are_described() {
+    let (_, changes) = normalise("\u{feff}a \r\nb\r\n\r\n");
+
+    assert_eq!(
+        changes.to_string(),
+        "removed a byte-order mark, converted 3 CRLF line endings, \
+         trimmed trailing whitespace from 1 line, dropped 1 trailing blank line"
+    );
+}
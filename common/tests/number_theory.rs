@@ -0,0 +1,62 @@
+use aoc_common::number_theory::{crt, extended_gcd, gcd, lcm, mod_inverse, mod_pow};
+
+#[test]
+fn extended_gcd_gives_bezout_coefficients() {
+    for (a, b) in [(240, 46), (46, 240), (17, 5), (0, 7), (7, 0), (-12, 18)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert!(g >= 0);
+        assert_eq!(a * x + b * y, g, "a = {}, b = {}", a, b);
+    }
+
+    assert_eq!(extended_gcd(240, 46).0, 2);
+    assert_eq!(extended_gcd(-12, 18).0, 6);
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(0, 5), 5);
+    assert_eq!(lcm(4, 6), Some(12));
+    assert_eq!(lcm(0, 6), Some(0));
+    assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+}
+
+#[test]
+fn modular_inverse() {
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(6, 9), None);
+    assert_eq!(mod_inverse(1, 0), None);
+}
+
+#[test]
+fn modular_exponentiation() {
+    assert_eq!(mod_pow(4, 13, 497), 445);
+    assert_eq!(mod_pow(7, 0, 13), 1);
+    assert_eq!(mod_pow(7, 0, 1), 0);
+    assert_eq!(mod_pow(u64::MAX, u64::MAX, 1_000_000_007), 254_368_884);
+}
+
+#[test]
+fn chinese_remainder() {
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt(&[]), Some((0, 1)));
+
+    // Moduli sharing a factor are fine as long as they agree.
+    assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+    assert_eq!(crt(&[(3, 4), (2, 6)]), None);
+
+    assert_eq!(crt(&[(1, 0)]), None);
+    assert_eq!(crt(&[(1, u64::MAX), (0, u64::MAX - 1)]), None);
+}
+
+#[test]
+fn chinese_remainder_with_large_moduli() {
+    let p = 4_294_967_291;
+    let q = 4_294_967_279;
+    let (x, m) = crt(&[(p - 1, p), (q - 2, q)]).unwrap();
+
+    assert_eq!(m, p * q);
+    assert_eq!(x % p, p - 1);
+    assert_eq!(x % q, q - 2);
+}
@@ -0,0 +1,47 @@
+use aoc_common::Record;
+
+fn lines(text: &str) -> Vec<String> {
+    text.split('\n').map(String::from).collect()
+}
+
+fn spans(lines: &[String]) -> Vec<(usize, usize)> {
+    aoc_common::records(lines)
+        .map(|record| (*record.span().start(), *record.span().end()))
+        .collect()
+}
+
+#[test]
+fn splits_on_blank_lines() {
+    let lines = lines("a\nb\n\nc");
+    let records: Vec<Record> = aoc_common::records(&lines).collect();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].lines, &lines[0..2]);
+    assert_eq!(records[1].lines, &lines[3..4]);
+    assert_eq!(spans(&lines), vec![(1, 2), (4, 4)]);
+}
+
+#[test]
+fn tolerates_extra_blank_lines() {
+    let lines = lines("\n\na\n\n\n  \nb\nc\n\n");
+
+    assert_eq!(spans(&lines), vec![(3, 3), (7, 8)]);
+}
+
+#[test]
+fn yields_nothing_for_blank_input() {
+    assert_eq!(spans(&[]), vec![]);
+    assert_eq!(spans(&lines("\n\n")), vec![]);
+}
+
+#[test]
+fn numbers_each_line() {
+    let lines = lines("\nx\ny");
+    let record = aoc_common::records(&lines).next().unwrap();
+
+    let numbered: Vec<(usize, &str)> = record
+        .numbered()
+        .map(|(n, line)| (n, line.as_str()))
+        .collect();
+    assert_eq!(numbered, vec![(2, "x"), (3, "y")]);
+}
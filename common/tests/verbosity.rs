@@ -0,0 +1,46 @@
+use aoc_common::Verbosity;
+
+fn verbosity(args: &[&str]) -> Verbosity {
+    let matches = aoc_common::app(1)
+        .get_matches_from_safe(std::iter::once("day1").chain(args.iter().copied()))
+        .unwrap();
+    aoc_common::verbosity(&matches)
+}
+
+#[test]
+fn flags_select_the_verbosity() {
+    assert_eq!(verbosity(&[]), Verbosity::Normal);
+    assert_eq!(verbosity(&["-q"]), Verbosity::Quiet);
+    assert_eq!(verbosity(&["-v"]), Verbosity::Verbose);
+    assert_eq!(verbosity(&["-vv"]), Verbosity::Trace);
+    assert_eq!(verbosity(&["-v", "--verbose", "-v"]), Verbosity::Trace);
+}
+
+#[test]
+fn quiet_and_verbose_conflict() {
+    assert!(aoc_common::app(1)
+        .get_matches_from_safe(vec!["day1", "-q", "-v"])
+        .is_err());
+}
+
+#[test]
+fn levels_up_to_the_verbosity_are_enabled() {
+    aoc_common::set_verbosity(Verbosity::Verbose);
+    assert!(aoc_common::enabled(Verbosity::Normal));
+    assert!(aoc_common::enabled(Verbosity::Verbose));
+    assert!(!aoc_common::enabled(Verbosity::Trace));
+
+    aoc_common::set_verbosity(Verbosity::Quiet);
+    assert!(!aoc_common::enabled(Verbosity::Normal));
+    assert_eq!(aoc_common::current_verbosity(), Verbosity::Quiet);
+}
+
+#[test]
+fn held_messages_are_released_in_order() {
+    aoc_common::hold_log();
+    aoc_common::write_log(format_args!("first {}", 1));
+    aoc_common::write_log(format_args!("second"));
+
+    assert_eq!(aoc_common::release_log(), ["first 1", "second"]);
+    assert!(aoc_common::release_log().is_empty());
+}
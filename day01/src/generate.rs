@@ -0,0 +1,75 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::{Day1, Entries};
+
+const TARGET: u32 = 2020;
+
+/// Plants a pair and a triple of small entries that sum to 2020.
+///
+/// Every other entry is more than half of 2020, so no two of them can
+/// make a pair and none can take part in a triple with another. The only
+/// other sums to rule out are those mixing them with the planted entries.
+impl Generator for Day1 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let (pair, triple) = loop {
+            let small = rng.range(1..TARGET as u64 / 2) as u32;
+            let pair = [small, TARGET - small];
+
+            let a = rng.range(1..TARGET as u64 / 3) as u32;
+            let b = rng.range(a as u64 + 1..(TARGET - a) as u64 / 2) as u32;
+            let triple = [a, b, TARGET - a - b];
+
+            // The planted entries must not make any other pair or triple.
+            let mut planted: Vec<u32> = pair.iter().chain(triple.iter()).copied().collect();
+            planted.sort_unstable();
+            planted.dedup();
+            if planted.len() == 5 && sums(&planted) == 2 {
+                break (pair, triple);
+            }
+        };
+
+        let planted: Vec<u32> = pair.iter().chain(triple.iter()).copied().collect();
+        let mut entries = planted.clone();
+        while entries.len() < size.max(planted.len()) {
+            let entry = rng.range(TARGET as u64 / 2 + 1..TARGET as u64) as u32;
+            let clashes = planted.iter().any(|x| {
+                x + entry == TARGET || planted.iter().any(|y| x != y && x + y + entry == TARGET)
+            });
+            if !clashes {
+                entries.push(entry);
+            }
+        }
+        rng.shuffle(&mut entries);
+
+        Generated {
+            lines: entries.iter().map(|e| e.to_string()).collect(),
+            answers: Answers {
+                part1: Some(Entries(pair.to_vec()).to_string()),
+                part2: Some(Entries(triple.to_vec()).to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        200
+    }
+}
+
+/// Counts the pairs and triples of distinct entries that sum to 2020.
+fn sums(entries: &[u32]) -> usize {
+    let mut count = 0;
+
+    for (i, x) in entries.iter().enumerate() {
+        for (j, y) in entries.iter().enumerate().skip(i + 1) {
+            if x + y == TARGET {
+                count += 1;
+            }
+            count += entries[j + 1..]
+                .iter()
+                .filter(|z| x + y + *z == TARGET)
+                .count();
+        }
+    }
+
+    count
+}
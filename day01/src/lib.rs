@@ -0,0 +1,117 @@
+use std::fmt;
+
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{trace, Answer, Error, Part, Result, Solution};
+
+mod generate;
+
+pub struct Entries(pub Vec<u32>);
+
+impl Entries {
+    pub fn sum(&self) -> u32 {
+        self.0.iter().sum()
+    }
+
+    pub fn product(&self) -> u32 {
+        self.0.iter().product()
+    }
+}
+
+impl fmt::Display for Entries {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.product())
+    }
+}
+
+impl Answer for Entries {
+    fn diagnostics(&self) -> Option<Value> {
+        Some(json!({ "entries": self.0 }))
+    }
+}
+
+/// Whether `values` sum to 2020. Large entries may overflow a `u32`, and
+/// then they cannot.
+fn sums_to_2020(values: &[u32]) -> bool {
+    let sum = values.iter().try_fold(0u32, |sum, v| sum.checked_add(*v));
+    trace!(
+        "{} = {}",
+        values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" + "),
+        sum.map_or_else(|| String::from("overflow"), |sum| sum.to_string())
+    );
+    sum == Some(2020)
+}
+
+fn find_pair(values: &[u32]) -> Option<Entries> {
+    for (i, x) in values.iter().enumerate() {
+        for y in values.iter().skip(i + 1) {
+            if sums_to_2020(&[*x, *y]) {
+                return Some(Entries(vec![*x, *y]));
+            }
+        }
+    }
+
+    None
+}
+
+fn find_triple(values: &[u32]) -> Option<Entries> {
+    for (i, x) in values.iter().enumerate() {
+        for (j, y) in values.iter().skip(i + 1).enumerate() {
+            for z in values.iter().skip(i + j + 2) {
+                if sums_to_2020(&[*x, *y, *z]) {
+                    return Some(Entries(vec![*x, *y, *z]));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+    type Part1 = Entries;
+    type Part2 = Entries;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| aoc_common::parse_field(line, line))
+    }
+
+    fn part1(&self, values: &Self::Input) -> Result<Self::Part1> {
+        find_pair(values).ok_or_else(|| Error::no_solution("no pair of entries sums to 2020"))
+    }
+
+    fn part2(&self, values: &Self::Input) -> Result<Self::Part2> {
+        find_triple(values).ok_or_else(|| Error::no_solution("no triple of entries sums to 2020"))
+    }
+}
+
+fn print_entries(entries: &Entries) {
+    let values: Vec<String> = entries.0.iter().map(|v| v.to_string()).collect();
+    println!(
+        "{} = {} -- {} = {}",
+        values.join(" + "),
+        entries.sum(),
+        values.join(" * "),
+        entries.product()
+    );
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let values = Day1.parse(lines)?;
+
+    if part.one() {
+        print_entries(&Day1.part1(&values)?);
+    }
+
+    if part.two() {
+        print_entries(&Day1.part2(&values)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::Day2;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// Builds each password to be valid or not under each policy by choice, so
+/// both counts are known.
+impl Generator for Day2 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let mut valid_v1 = 0;
+        let mut valid_v2 = 0;
+
+        let lines = (0..size)
+            .map(|_| {
+                let low = rng.range(1..10) as usize;
+                let high = rng.range(low as u64 + 1..low as u64 + 10) as usize;
+                let character = *rng.choose(LETTERS);
+                let v1 = rng.chance(1, 2);
+                let v2 = rng.chance(1, 2);
+                valid_v1 += v1 as usize;
+                valid_v2 += v2 as usize;
+
+                let password = password(rng, low, high, character, v1, v2);
+                format!("{}-{} {}: {}", low, high, character as char, password)
+            })
+            .collect();
+
+        Generated {
+            lines,
+            answers: Answers {
+                part1: Some(valid_v1.to_string()),
+                part2: Some(valid_v2.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        1000
+    }
+}
+
+fn password(rng: &mut Rng, low: usize, high: usize, character: u8, v1: bool, v2: bool) -> String {
+    let len = high + 3 + rng.index(5);
+    let others: Vec<u8> = LETTERS
+        .iter()
+        .copied()
+        .filter(|c| *c != character)
+        .collect();
+
+    // The second policy only looks at the two positions, and wants exactly
+    // one of them to hold the character.
+    let (at_low, at_high) = match (v2, rng.chance(1, 2)) {
+        (true, first) => (first, !first),
+        (false, both) => (both, both),
+    };
+    let fixed = at_low as usize + at_high as usize;
+
+    // The first policy counts the character everywhere, so choose how many
+    // of the remaining positions hold it.
+    let count = if v1 {
+        rng.range(low.max(fixed) as u64..high as u64 + 1) as usize
+    } else if fixed < low && rng.chance(1, 2) {
+        rng.range(fixed as u64..low as u64) as usize
+    } else {
+        rng.range((high + 1).max(fixed) as u64..(len - 2 + fixed) as u64 + 1) as usize
+    };
+
+    let mut rest: Vec<u8> = (0..len - 2)
+        .map(|i| {
+            if i < count - fixed {
+                character
+            } else {
+                *rng.choose(&others)
+            }
+        })
+        .collect();
+    rng.shuffle(&mut rest);
+
+    let mut rest = rest.into_iter();
+    (1..=len)
+        .map(|position| {
+            let c = match position {
+                p if p == low => pick(rng, at_low, character, &others),
+                p if p == high => pick(rng, at_high, character, &others),
+                _ => rest.next().unwrap(),
+            };
+            c as char
+        })
+        .collect()
+}
+
+fn pick(rng: &mut Rng, wanted: bool, character: u8, others: &[u8]) -> u8 {
+    if wanted {
+        character
+    } else {
+        *rng.choose(others)
+    }
+}
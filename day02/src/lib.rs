@@ -0,0 +1,110 @@
+use std::convert::TryFrom;
+
+use aoc_common::{trace, Error, Part, Result, Solution};
+
+mod generate;
+
+pub struct Policy {
+    low: usize,
+    high: usize,
+    character: char,
+    password: String,
+}
+
+impl Policy {
+    fn is_valid_v1(&self) -> bool {
+        let char_count = self
+            .password
+            .chars()
+            .filter(|c| *c == self.character)
+            .count();
+        char_count >= self.low && char_count <= self.high
+    }
+
+    fn is_valid_v2(&self) -> bool {
+        match (
+            self.password.chars().nth(self.low - 1),
+            self.password.chars().nth(self.high - 1),
+        ) {
+            (Some(a), Some(b)) if a == self.character && a != b => true,
+            (Some(a), Some(b)) if b == self.character && a != b => true,
+            _ => false,
+        }
+    }
+}
+
+impl TryFrom<&str> for Policy {
+    type Error = Error;
+
+    fn try_from(line: &str) -> Result<Self> {
+        let bits: Vec<&str> = line.split_whitespace().collect();
+        if bits.len() != 3 {
+            return Err(Error::bad_input(
+                "expected a range, a character and a password",
+            ));
+        }
+
+        let (low, high) = bits[0].split_once('-').ok_or_else(|| {
+            Error::bad_input("expected a range such as 1-3")
+                .column(aoc_common::column(line, bits[0]))
+        })?;
+        let low: usize = aoc_common::parse_field(line, low)?;
+        let high: usize = aoc_common::parse_field(line, high)?;
+        if low == 0 || high < low {
+            return Err(Error::bad_input(format!("bad range {}-{}", low, high))
+                .column(aoc_common::column(line, bits[0])));
+        }
+
+        let character = match bits[1].strip_suffix(':') {
+            Some(c) if c.chars().count() == 1 => c.chars().next().unwrap(),
+            _ => {
+                return Err(Error::bad_input("expected a single character and a colon")
+                    .column(aoc_common::column(line, bits[1])))
+            }
+        };
+        let password = bits[2].to_string();
+
+        trace!("{} - {} {} {}", low, high, character, password);
+
+        Ok(Self {
+            low,
+            high,
+            character,
+            password,
+        })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Policy>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| Policy::try_from(line))
+    }
+
+    fn part1(&self, policies: &Self::Input) -> Result<Self::Part1> {
+        Ok(policies.iter().filter(|p| p.is_valid_v1()).count())
+    }
+
+    fn part2(&self, policies: &Self::Input) -> Result<Self::Part2> {
+        Ok(policies.iter().filter(|p| p.is_valid_v2()).count())
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let policies = Day2.parse(lines)?;
+
+    if part.one() {
+        println!("{} good passwords (v1)", Day2.part1(&policies)?);
+    }
+
+    if part.two() {
+        println!("{} good passwords (v2)", Day2.part2(&policies)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use aoc_common::{Answers, Generated, Generator, Grid, Rng};
+
+use crate::{Day3, SlopeCounts, SLOPES};
+
+/// Scatters trees at random, counting those on each slope's path as they
+/// are placed.
+///
+/// Part 2 is left unplanted on fields so tall that the product of the
+/// counts is too large to hold.
+impl Generator for Day3 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let width = 31;
+        let height = size.max(1);
+        let mut field = Grid::new(width, height, false);
+        for y in 0..height {
+            for x in 0..width {
+                field[(x, y)] = rng.chance(1, 4);
+            }
+        }
+
+        let slopes: Vec<_> = SLOPES
+            .iter()
+            .map(|(right, down)| {
+                let trees = (0..height.div_ceil(*down))
+                    .filter(|step| field[((step * right) % width, step * down)])
+                    .count();
+                ((*right, *down), trees)
+            })
+            .collect();
+
+        Generated {
+            lines: field
+                .rows()
+                .map(|row| {
+                    row.iter()
+                        .map(|tree| if *tree { '#' } else { '.' })
+                        .collect()
+                })
+                .collect(),
+            answers: Answers {
+                part1: Some(slopes[1].1.to_string()),
+                part2: SlopeCounts::new(slopes).map(|counts| counts.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        323
+    }
+}
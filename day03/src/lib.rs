@@ -0,0 +1,112 @@
+use std::fmt;
+
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{Answer, Error, Grid, Part, Result, Solution};
+
+mod generate;
+
+const SLOPES: &[(usize, usize)] = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+pub struct TreeField(Grid<bool>);
+
+impl TreeField {
+    fn count_trees(&self, right: usize, down: usize) -> usize {
+        (0..self.0.height())
+            .step_by(down)
+            .enumerate()
+            .filter(|(step, y)| *self.0.get_wrapped((step * right) as isize, *y as isize))
+            .count()
+    }
+}
+
+pub struct SlopeCounts {
+    pub slopes: Vec<((usize, usize), usize)>,
+    product: usize,
+}
+
+impl SlopeCounts {
+    /// The tree counts for each slope, or `None` if their product is too
+    /// large.
+    pub fn new(slopes: Vec<((usize, usize), usize)>) -> Option<Self> {
+        let product = slopes
+            .iter()
+            .try_fold(1usize, |product, (_, count)| product.checked_mul(*count))?;
+        Some(Self { slopes, product })
+    }
+
+    /// The product of the tree counts.
+    pub fn product(&self) -> usize {
+        self.product
+    }
+}
+
+impl fmt::Display for SlopeCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.product)
+    }
+}
+
+impl Answer for SlopeCounts {
+    fn diagnostics(&self) -> Option<Value> {
+        Some(json!({
+            "slopes": self
+                .slopes
+                .iter()
+                .map(|((right, down), trees)| {
+                    json!({ "right": right, "down": down, "trees": trees })
+                })
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = TreeField;
+    type Part1 = usize;
+    type Part2 = SlopeCounts;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let field = Grid::parse(lines, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(TreeField(field))
+    }
+
+    fn part1(&self, field: &Self::Input) -> Result<Self::Part1> {
+        Ok(field.count_trees(3, 1))
+    }
+
+    fn part2(&self, field: &Self::Input) -> Result<Self::Part2> {
+        SlopeCounts::new(
+            SLOPES
+                .iter()
+                .map(|(right, down)| ((*right, *down), field.count_trees(*right, *down)))
+                .collect(),
+        )
+        .ok_or_else(|| Error::too_large("the product of the tree counts"))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let field = Day3.parse(lines)?;
+
+    if part.one() {
+        println!("Right 3, down 1: {} trees", Day3.part1(&field)?);
+    }
+
+    if part.two() {
+        let counts = Day3.part2(&field)?;
+
+        for ((right, down), tree_count) in counts.slopes.iter() {
+            println!("Right {}, down {}: {} trees", right, down, tree_count);
+        }
+        println!("Final product: {}", counts);
+    }
+
+    Ok(())
+}
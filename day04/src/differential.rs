@@ -0,0 +1,156 @@
+use aoc_common::differential::{removals, Differential};
+use aoc_common::{Rng, Solution};
+
+use crate::generate::{bad_value, good_value, EYE_COLOURS, REQUIRED};
+use crate::Day4;
+
+/// A passport's fields, in the order they are written.
+type Fields = Vec<(String, String)>;
+
+/// Counts the passports `fields` makes under both sets of rules.
+fn counts(fields: &Fields) -> Option<(usize, usize)> {
+    let line: Vec<String> = fields.iter().map(|(k, v)| format!("{}:{}", k, v)).collect();
+    let passports = Day4.parse(&[line.join(" ")]).ok()?;
+    Some((Day4.part1(&passports).ok()?, Day4.part2(&passports).ok()?))
+}
+
+fn fields(rng: &mut Rng, value: impl Fn(&mut Rng, &str) -> String) -> Fields {
+    let mut fields = Fields::new();
+    for key in REQUIRED {
+        if rng.chance(7, 8) {
+            let value = value(rng, key);
+            fields.push((key.to_string(), value));
+        }
+    }
+    if rng.chance(1, 2) {
+        fields.push((String::from("cid"), rng.range(1..400).to_string()));
+    }
+    rng.shuffle(&mut fields);
+    fields
+}
+
+/// Ways of making `fields` smaller: leaving a field out, or dropping a
+/// character from a value.
+fn smaller(fields: &Fields) -> Vec<Fields> {
+    let mut smaller = removals(fields);
+
+    for (i, (_, value)) in fields.iter().enumerate() {
+        for (j, _) in value.char_indices() {
+            let mut shorter = fields.clone();
+            shorter[i].1.remove(j);
+            smaller.push(shorter);
+        }
+    }
+
+    smaller
+}
+
+/// The simple rules only check which fields are present, so on passports
+/// whose values are all good they must agree with the strict rules.
+pub struct SimpleVsStrict;
+
+impl Differential for SimpleVsStrict {
+    const NAME: &'static str = "day 4: simple vs strict";
+    const LEFT: &'static str = "simple";
+    const RIGHT: &'static str = "strict";
+
+    type Input = Fields;
+    type Output = Option<usize>;
+
+    fn generate(&self, rng: &mut Rng, _size: usize) -> Self::Input {
+        fields(rng, good_value)
+    }
+
+    fn shrink(&self, fields: &Self::Input) -> Vec<Self::Input> {
+        removals(fields)
+    }
+
+    fn left(&self, fields: &Self::Input) -> Self::Output {
+        counts(fields).map(|(simple, _)| simple)
+    }
+
+    fn right(&self, fields: &Self::Input) -> Self::Output {
+        counts(fields).map(|(_, strict)| strict)
+    }
+}
+
+/// Checks the strict rules against a reading of the puzzle text that
+/// follows it word for word.
+pub struct StrictVsRules;
+
+impl Differential for StrictVsRules {
+    const NAME: &'static str = "day 4: strict vs rules";
+    const LEFT: &'static str = "strict";
+    const RIGHT: &'static str = "rules";
+
+    type Input = Fields;
+    type Output = Option<bool>;
+
+    fn generate(&self, rng: &mut Rng, _size: usize) -> Self::Input {
+        fields(rng, |rng, key| match rng.index(4) {
+            0 => bad_value(rng, key),
+            1 => {
+                let value = good_value(rng, key);
+                mutate(rng, value)
+            }
+            _ => good_value(rng, key),
+        })
+    }
+
+    fn shrink(&self, fields: &Self::Input) -> Vec<Self::Input> {
+        smaller(fields)
+    }
+
+    fn left(&self, fields: &Self::Input) -> Self::Output {
+        counts(fields).map(|(_, strict)| strict == 1)
+    }
+
+    fn right(&self, fields: &Self::Input) -> Self::Output {
+        counts(fields).map(|_| follows_rules(fields))
+    }
+}
+
+/// Changes one character of `value` in the ways that tend to slip past
+/// number parsing.
+fn mutate(rng: &mut Rng, value: String) -> String {
+    let mut chars: Vec<char> = value.chars().collect();
+    let i = rng.index(chars.len());
+    match rng.index(3) {
+        0 => chars[i] = chars[i].to_ascii_uppercase(),
+        1 => chars.insert(i, *rng.choose(&['+', '-', '0', ' '])),
+        _ => chars[i] = *rng.choose(&['+', 'f', 'g', '9']),
+    }
+    chars.into_iter().filter(|c| *c != ' ').collect()
+}
+
+fn number_between(value: &str, digits: Option<usize>, low: u32, high: u32) -> bool {
+    !value.is_empty()
+        && digits.is_none_or(|d| value.len() == d)
+        && value.chars().all(|c| c.is_ascii_digit())
+        && value.parse().is_ok_and(|n: u32| (low..=high).contains(&n))
+}
+
+fn follows_rules(fields: &Fields) -> bool {
+    REQUIRED.iter().all(|required| {
+        fields.iter().any(|(key, value)| {
+            key == required
+                && match key.as_str() {
+                    "byr" => number_between(value, Some(4), 1920, 2002),
+                    "iyr" => number_between(value, Some(4), 2010, 2020),
+                    "eyr" => number_between(value, Some(4), 2020, 2030),
+                    "hgt" => match value.strip_suffix("cm") {
+                        Some(cm) => number_between(cm, None, 150, 193),
+                        None => value
+                            .strip_suffix("in")
+                            .is_some_and(|inches| number_between(inches, None, 59, 76)),
+                    },
+                    "hcl" => value.strip_prefix('#').is_some_and(|hex| {
+                        hex.len() == 6 && hex.chars().all(|c| "0123456789abcdef".contains(c))
+                    }),
+                    "ecl" => EYE_COLOURS.contains(&value.as_str()),
+                    "pid" => value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()),
+                    _ => false,
+                }
+        })
+    })
+}
@@ -0,0 +1,136 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::Day4;
+
+pub(crate) const REQUIRED: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+pub(crate) const EYE_COLOURS: &[&str] = &["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+enum Kind {
+    /// Valid under both sets of rules.
+    Valid,
+    /// Has every field, but one of them has a bad value.
+    BadValue,
+    /// Is missing a required field.
+    MissingField,
+}
+
+/// Chooses up front whether each passport is valid, has a bad value or is
+/// missing a field, so both counts are known.
+impl Generator for Day4 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let mut lines = Vec::new();
+        let mut complete = 0;
+        let mut valid = 0;
+
+        for n in 0..size {
+            if n > 0 {
+                lines.push(String::new());
+            }
+
+            let kind = match rng.index(3) {
+                0 => Kind::Valid,
+                1 => Kind::BadValue,
+                _ => Kind::MissingField,
+            };
+            match kind {
+                Kind::Valid => {
+                    complete += 1;
+                    valid += 1;
+                }
+                Kind::BadValue => complete += 1,
+                Kind::MissingField => (),
+            }
+
+            lines.extend(passport(rng, &kind));
+        }
+
+        Generated {
+            lines,
+            answers: Answers {
+                part1: Some(complete.to_string()),
+                part2: Some(valid.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        250
+    }
+}
+
+fn passport(rng: &mut Rng, kind: &Kind) -> Vec<String> {
+    let mut fields: Vec<(&str, String)> = REQUIRED
+        .iter()
+        .map(|key| (*key, good_value(rng, key)))
+        .collect();
+
+    match kind {
+        Kind::Valid => (),
+        Kind::BadValue => {
+            let field = &mut fields[rng.index(REQUIRED.len())];
+            field.1 = bad_value(rng, field.0);
+        }
+        Kind::MissingField => {
+            fields.remove(rng.index(REQUIRED.len()));
+            // What is left need not be valid either.
+            if rng.chance(1, 2) {
+                let field = &mut fields[rng.index(REQUIRED.len() - 1)];
+                field.1 = bad_value(rng, field.0);
+            }
+        }
+    }
+
+    if rng.chance(1, 2) {
+        fields.push(("cid", rng.range(1..400).to_string()));
+    }
+    rng.shuffle(&mut fields);
+
+    // Spread the fields over one to three lines.
+    let mut lines = vec![Vec::new(); rng.range(1..4) as usize];
+    let count = lines.len();
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        let line = if i < count { i } else { rng.index(count) };
+        lines[line].push(format!("{}:{}", key, value));
+    }
+
+    lines.into_iter().map(|entries| entries.join(" ")).collect()
+}
+
+pub(crate) fn good_value(rng: &mut Rng, key: &str) -> String {
+    match key {
+        "byr" => rng.range(1920..2003).to_string(),
+        "iyr" => rng.range(2010..2021).to_string(),
+        "eyr" => rng.range(2020..2031).to_string(),
+        "hgt" if rng.chance(1, 2) => format!("{}cm", rng.range(150..194)),
+        "hgt" => format!("{}in", rng.range(59..77)),
+        "hcl" => format!("#{:06x}", rng.range(0..1 << 24)),
+        "ecl" => rng.choose(EYE_COLOURS).to_string(),
+        "pid" => format!("{:09}", rng.range(0..1_000_000_000)),
+        _ => unreachable!("no rules for {}", key),
+    }
+}
+
+pub(crate) fn bad_value(rng: &mut Rng, key: &str) -> String {
+    let choices: &[String] = &match key {
+        "byr" => vec![rng.range(1850..1920).to_string(), String::from("19x5")],
+        "iyr" => vec![rng.range(2021..2030).to_string(), String::from("201")],
+        "eyr" => vec![rng.range(2031..2040).to_string(), String::from("1999")],
+        "hgt" => vec![
+            format!("{}cm", rng.range(194..250)),
+            format!("{}in", rng.range(20..59)),
+            rng.range(59..194).to_string(),
+        ],
+        "hcl" => vec![
+            format!("{:06x}", rng.range(0..1 << 24)),
+            String::from("#12345z"),
+        ],
+        "ecl" => vec![String::from("xry"), String::from("blue")],
+        "pid" => vec![
+            format!("{:08}", rng.range(0..100_000_000)),
+            format!("{:010}", rng.range(0..10_000_000_000)),
+        ],
+        _ => unreachable!("no rules for {}", key),
+    };
+
+    rng.choose(choices).clone()
+}
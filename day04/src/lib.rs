@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use aoc_common::{Error, Part, Result, Solution};
+
+pub mod differential;
+mod generate;
+pub mod simple;
+pub mod strict;
+
+#[derive(Debug)]
+pub enum PassportError {
+    MissingField(String),
+    BadValue,
+}
+
+impl From<std::num::ParseIntError> for PassportError {
+    fn from(_error: std::num::ParseIntError) -> Self {
+        Self::BadValue
+    }
+}
+
+#[macro_export]
+macro_rules! get_field {
+    ($map:ident, $name:expr) => {
+        match $map.get($name) {
+            Some(v) => v.to_owned(),
+            None => return Err(PassportError::MissingField($name.to_string())),
+        }
+    };
+    ($map:ident, $name:expr, $len:expr) => {
+        match $map.get($name) {
+            Some(v) => {
+                if v.len() != $len {
+                    return Err(PassportError::BadValue);
+                } else {
+                    v.to_owned()
+                }
+            }
+            None => return Err(PassportError::MissingField($name.to_string())),
+        }
+    };
+}
+
+fn count_valid<P>(passports: &[HashMap<String, String>]) -> usize
+where
+    P: TryFrom<HashMap<String, String>>,
+{
+    passports
+        .iter()
+        .filter(|data| P::try_from((*data).clone()).is_ok())
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<HashMap<String, String>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::records(lines)
+            .map(|record| {
+                let mut data: HashMap<String, String> = HashMap::new();
+
+                for (n, line) in record.numbered() {
+                    for entry in line.split_whitespace() {
+                        let bad = |cause: String| {
+                            Error::bad_input(cause)
+                                .line(n)
+                                .column(aoc_common::column(line, entry))
+                        };
+
+                        let (key, value) = entry
+                            .split_once(':')
+                            .ok_or_else(|| bad(format!("expected key:value, got {:?}", entry)))?;
+
+                        if data.insert(key.to_string(), value.to_string()).is_some() {
+                            let span = record.span();
+                            return Err(bad(format!(
+                                "{} appears twice in the passport on lines {} to {}",
+                                key,
+                                span.start(),
+                                span.end()
+                            )));
+                        }
+                    }
+                }
+
+                Ok(data)
+            })
+            .collect()
+    }
+
+    fn part1(&self, passports: &Self::Input) -> Result<Self::Part1> {
+        Ok(count_valid::<simple::Passport>(passports))
+    }
+
+    fn part2(&self, passports: &Self::Input) -> Result<Self::Part2> {
+        Ok(count_valid::<strict::Passport>(passports))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let passports = Day4.parse(lines)?;
+
+    if part.one() {
+        println!("{} valid", Day4.part1(&passports)?);
+    }
+
+    if part.two() {
+        println!("{} valid", Day4.part2(&passports)?);
+    }
+
+    Ok(())
+}
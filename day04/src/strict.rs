@@ -4,6 +4,16 @@ use std::str::FromStr;
 
 use crate::{get_field, PassportError};
 
+/// Parses a number written only in digits, which `u32::from_str` alone
+/// does not insist on as it also takes a leading `+`.
+fn number(value: &str) -> Result<u32, PassportError> {
+    if !value.chars().all(|c| c.is_ascii_digit()) {
+        return Err(PassportError::BadValue);
+    }
+    Ok(u32::from_str(value)?)
+}
+
+#[allow(dead_code)]
 enum Height {
     Centimetres(u32),
     Inches(u32),
@@ -14,14 +24,14 @@ impl TryFrom<&str> for Height {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         if let Some(h) = value.strip_suffix("cm") {
-            let h = u32::from_str(h)?;
-            if h < 150 || h > 193 {
+            let h = number(h)?;
+            if !(150..=193).contains(&h) {
                 return Err(PassportError::BadValue);
             }
             Ok(Self::Centimetres(h))
         } else if let Some(h) = value.strip_suffix("in") {
-            let h = u32::from_str(h)?;
-            if h < 59 || h > 76 {
+            let h = number(h)?;
+            if !(59..=76).contains(&h) {
                 return Err(PassportError::BadValue);
             }
             Ok(Self::Inches(h))
@@ -74,34 +84,35 @@ impl TryFrom<HashMap<String, String>> for Passport {
     type Error = PassportError;
 
     fn try_from(map: HashMap<String, String>) -> Result<Self, Self::Error> {
-        let byr = u32::from_str(get_field!(map, "byr", 4).as_str())?;
-        let iyr = u32::from_str(get_field!(map, "iyr", 4).as_str())?;
-        let eyr = u32::from_str(get_field!(map, "eyr", 4).as_str())?;
+        let byr = number(get_field!(map, "byr", 4).as_str())?;
+        let iyr = number(get_field!(map, "iyr", 4).as_str())?;
+        let eyr = number(get_field!(map, "eyr", 4).as_str())?;
         let hgt = Height::try_from(get_field!(map, "hgt").as_str())?;
         let hcl = get_field!(map, "hcl", 7);
         let ecl = EyeColour::try_from(get_field!(map, "ecl", 3).as_str())?;
         let pid = get_field!(map, "pid", 9);
         let cid = map.get("cid").map(|v| v.to_owned());
 
-        if byr < 1920 || byr > 2002 {
+        if !(1920..=2002).contains(&byr) {
             return Err(PassportError::BadValue);
         }
 
-        if iyr < 2010 || iyr > 2020 {
+        if !(2010..=2020).contains(&iyr) {
             return Err(PassportError::BadValue);
         }
 
-        if eyr < 2020 || eyr > 2030 {
+        if !(2020..=2030).contains(&eyr) {
             return Err(PassportError::BadValue);
         }
 
-        let hcl = if let Some(c) = hcl.strip_prefix('#') {
-            u32::from_str_radix(c, 16)?
-        } else {
-            return Err(PassportError::BadValue);
+        let hcl = match hcl.strip_prefix('#') {
+            Some(c) if c.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')) => {
+                u32::from_str_radix(c, 16)?
+            }
+            _ => return Err(PassportError::BadValue),
         };
 
-        u32::from_str(&pid)?;
+        number(&pid)?;
 
         Ok(Passport {
             byr,
@@ -0,0 +1,48 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::{Day5, MAX_COLUMN, MAX_ROW};
+
+const SEATS: usize = ((MAX_ROW + 1) * (MAX_COLUMN + 1)) as usize;
+
+/// Fills a run of consecutive seats, leaving out one in the middle.
+///
+/// The run never reaches the last two seats, as a free seat at the very
+/// end would look like it sat between two taken ones.
+impl Generator for Day5 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let taken = size.clamp(2, SEATS - 4);
+        let first = rng.range(1..(SEATS - 2 - taken) as u64) as u32;
+        let last = first + taken as u32;
+        let free = rng.range(first as u64 + 1..last as u64) as u32;
+
+        let mut seats: Vec<u32> = (first..=last).filter(|id| *id != free).collect();
+        rng.shuffle(&mut seats);
+
+        Generated {
+            lines: seats.into_iter().map(boarding_pass).collect(),
+            answers: Answers {
+                part1: Some(last.to_string()),
+                part2: Some(free.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        800
+    }
+}
+
+fn boarding_pass(seat_id: u32) -> String {
+    let row = (0..7).rev().map(|bit| {
+        if seat_id >> (bit + 3) & 1 == 1 {
+            'B'
+        } else {
+            'F'
+        }
+    });
+    let column = (0..3)
+        .rev()
+        .map(|bit| if seat_id >> bit & 1 == 1 { 'R' } else { 'L' });
+
+    row.chain(column).collect()
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use aoc_common::{trace, Error, Part, Result, Solution};
+
+mod generate;
+
+const MAX_ROW: u32 = 127;
+const MAX_COLUMN: u32 = 7;
+
+pub struct Seat {
+    row: u32,
+    column: u32,
+}
+
+impl Seat {
+    fn seat_id(&self) -> u32 {
+        self.row * 8 + self.column
+    }
+}
+
+impl TryFrom<&str> for Seat {
+    type Error = Error;
+
+    fn try_from(string: &str) -> Result<Self> {
+        if string.chars().count() != 10 {
+            return Err(Error::bad_input("expected 10 characters"));
+        }
+
+        for (i, character) in string.chars().enumerate() {
+            let expected = if i < 7 { ['F', 'B'] } else { ['L', 'R'] };
+            if !expected.contains(&character) {
+                return Err(Error::bad_input(format!(
+                    "expected {} or {}, got {:?}",
+                    expected[0], expected[1], character
+                ))
+                .column(i + 1));
+            }
+        }
+
+        let mut low = 0;
+        let mut high = MAX_ROW;
+
+        for character in string.chars().take(7) {
+            let diff = (high + 1 - low) / 2;
+            if character == 'F' {
+                high -= diff;
+            } else {
+                low += diff;
+            }
+        }
+
+        let row = low;
+
+        let mut low = 0;
+        let mut high = MAX_COLUMN;
+
+        for character in string.chars().skip(7) {
+            let diff = (high + 1 - low) / 2;
+            if character == 'L' {
+                high -= diff;
+            } else {
+                low += diff;
+            }
+        }
+
+        Ok(Self { row, column: low })
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Seat>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| {
+            let seat = Seat::try_from(line)?;
+            trace!(
+                "{} -> row {}, column {}, seat ID {}",
+                line,
+                seat.row,
+                seat.column,
+                seat.seat_id()
+            );
+
+            Ok(seat)
+        })
+    }
+
+    fn part1(&self, seats: &Self::Input) -> Result<Self::Part1> {
+        let mut max_id: u32 = 0;
+
+        for seat in seats {
+            if seat.seat_id() > max_id {
+                max_id = seat.seat_id();
+            }
+        }
+
+        Ok(max_id)
+    }
+
+    fn part2(&self, seats: &Self::Input) -> Result<Self::Part2> {
+        let mut free: HashSet<u32> = (0..(MAX_ROW + 1) * (MAX_COLUMN + 1)).collect();
+
+        for seat in seats {
+            free.remove(&seat.seat_id());
+        }
+
+        for seat_id in free.iter() {
+            if *seat_id == 0 || free.contains(&(seat_id - 1)) {
+                continue;
+            }
+            if free.contains(&(seat_id + 1)) {
+                continue;
+            }
+            return Ok(*seat_id);
+        }
+
+        Err(Error::no_solution("no free seat between two taken seats"))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let seats = Day5.parse(lines)?;
+
+    if part.one() {
+        println!("Max seat ID: {}", Day5.part1(&seats)?);
+    }
+
+    if part.two() {
+        println!("Seat ID is {}", Day5.part2(&seats)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::Day6;
+
+const QUESTIONS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// Chooses which questions each group answered and which of those every
+/// member answered, then shares the rest out so that no member has all of
+/// them.
+impl Generator for Day6 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let mut lines = Vec::new();
+        let mut anyone = 0;
+        let mut everyone = 0;
+
+        for n in 0..size {
+            if n > 0 {
+                lines.push(String::new());
+            }
+
+            let (group, answered, common) = group(rng);
+            anyone += answered;
+            everyone += common;
+            lines.extend(group);
+        }
+
+        Generated {
+            lines,
+            answers: Answers {
+                part1: Some(anyone.to_string()),
+                part2: Some(everyone.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        450
+    }
+}
+
+/// Returns a group's answers, how many questions anyone answered and how
+/// many everyone answered.
+fn group(rng: &mut Rng) -> (Vec<String>, usize, usize) {
+    loop {
+        let people = rng.range(1..6) as usize;
+        let mut questions = QUESTIONS.to_vec();
+        rng.shuffle(&mut questions);
+        questions.truncate(rng.range(1..27) as usize);
+
+        let common = if people == 1 {
+            questions.len()
+        } else {
+            rng.index(questions.len() + 1)
+        };
+
+        let mut answers: Vec<Vec<u8>> = vec![questions[..common].to_vec(); people];
+        for question in &questions[common..] {
+            // Give each remaining question to some, but not all, of the group.
+            let mut who: Vec<usize> = (0..people).collect();
+            rng.shuffle(&mut who);
+            for person in &who[..rng.range(1..people as u64) as usize] {
+                answers[*person].push(*question);
+            }
+        }
+
+        if answers.iter().any(|a| a.is_empty()) {
+            continue;
+        }
+
+        let lines = answers
+            .into_iter()
+            .map(|mut a| {
+                rng.shuffle(&mut a);
+                String::from_utf8(a).unwrap()
+            })
+            .collect();
+        return (lines, questions.len(), common);
+    }
+}
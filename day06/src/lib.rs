@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use aoc_common::{Error, Part, Result, Solution};
+
+mod generate;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Vec<HashSet<char>>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::records(lines)
+            .map(|group| {
+                group
+                    .numbered()
+                    .map(
+                        |(n, line)| match line.find(|c: char| !c.is_ascii_lowercase()) {
+                            Some(i) => Err(Error::bad_input("answers must be letters a to z")
+                                .line(n)
+                                .column(i + 1)),
+                            None => Ok(line.chars().collect()),
+                        },
+                    )
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(&self, groups: &Self::Input) -> Result<Self::Part1> {
+        Ok(groups
+            .iter()
+            .map(|group| {
+                let mut anyone: HashSet<char> = HashSet::new();
+                for answers in group {
+                    anyone.extend(answers);
+                }
+                anyone.len()
+            })
+            .sum())
+    }
+
+    fn part2(&self, groups: &Self::Input) -> Result<Self::Part2> {
+        Ok(groups
+            .iter()
+            .map(|group| {
+                let mut everyone: Option<HashSet<char>> = None;
+                for answers in group {
+                    everyone = match everyone {
+                        None => Some(answers.clone()),
+                        Some(e) => Some(e.intersection(answers).copied().collect()),
+                    };
+                }
+                everyone.map(|e| e.len()).unwrap_or(0)
+            })
+            .sum())
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let groups = Day6.parse(lines)?;
+
+    if part.one() {
+        println!("Sum v1: {}", Day6.part1(&groups)?);
+    }
+
+    if part.two() {
+        println!("Sum v2: {}", Day6.part2(&groups)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::Day7;
+
+const ADJECTIVES: &[&str] = &[
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+const COLOURS: &[&str] = &[
+    "aqua", "beige", "black", "blue", "bronze", "brown", "coral", "crimson", "cyan", "gold",
+    "gray", "green", "indigo", "lime", "magenta", "maroon", "olive", "orange", "plum", "red",
+    "salmon", "silver", "tan", "teal", "tomato", "violet", "white", "yellow",
+];
+const TARGET: &str = "shiny gold";
+
+/// The most bags any one bag may hold, which keeps every total well inside
+/// the `u32` that part 2 counts in.
+const MAX_TOTAL: u64 = 1_000_000;
+
+struct Bag {
+    colour: String,
+    contents: Vec<(u64, usize)>,
+    total: u64,
+    holds_target: bool,
+}
+
+/// Builds the rules from the innermost bags outwards, so each bag only
+/// holds bags that already exist and both answers can be tallied as it
+/// goes.
+impl Generator for Day7 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let size = size.max(1);
+        let target = rng.index(size);
+        let mut colours = colours(rng, size);
+        let mut bags: Vec<Bag> = Vec::with_capacity(size);
+
+        for n in 0..size {
+            let colour = if n == target {
+                String::from(TARGET)
+            } else {
+                colours.pop().unwrap()
+            };
+
+            let mut bag = Bag {
+                colour,
+                contents: Vec::new(),
+                total: 0,
+                holds_target: false,
+            };
+
+            if n > 0 && rng.chance(3, 4) {
+                let mut candidates: Vec<usize> = (0..n).collect();
+                rng.shuffle(&mut candidates);
+
+                for inner in candidates.into_iter().take(rng.range(1..5) as usize) {
+                    let count = rng.range(1..6);
+                    let total = bag.total + count * (1 + bags[inner].total);
+                    if total > MAX_TOTAL {
+                        continue;
+                    }
+
+                    bag.total = total;
+                    bag.holds_target |= inner == target || bags[inner].holds_target;
+                    bag.contents.push((count, inner));
+                }
+            }
+
+            bags.push(bag);
+        }
+
+        let mut lines: Vec<String> = bags.iter().map(|bag| rule(bag, &bags)).collect();
+        rng.shuffle(&mut lines);
+
+        Generated {
+            lines,
+            answers: Answers {
+                part1: Some(
+                    bags.iter()
+                        .filter(|bag| bag.holds_target)
+                        .count()
+                        .to_string(),
+                ),
+                part2: Some(bags[target].total.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        600
+    }
+}
+
+/// Returns `size - 1` distinct colours other than shiny gold.
+fn colours(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut colours: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLOURS
+                .iter()
+                .map(move |colour| format!("{} {}", adjective, colour))
+        })
+        .filter(|colour| colour != TARGET)
+        .collect();
+
+    // Number the colours once the plain names run out.
+    let plain = colours.len();
+    let mut n = 2;
+    while colours.len() < size {
+        colours.push(format!("{} {}", colours[colours.len() % plain], n));
+        if colours.len().is_multiple_of(plain) {
+            n += 1;
+        }
+    }
+
+    rng.shuffle(&mut colours);
+    colours.truncate(size - 1);
+    colours
+}
+
+fn rule(bag: &Bag, bags: &[Bag]) -> String {
+    if bag.contents.is_empty() {
+        return format!("{} bags contain no other bags.", bag.colour);
+    }
+
+    let contents: Vec<String> = bag
+        .contents
+        .iter()
+        .map(|(count, inner)| {
+            let noun = if *count == 1 { "bag" } else { "bags" };
+            format!("{} {} {}", count, bags[*inner].colour, noun)
+        })
+        .collect();
+    format!("{} bags contain {}.", bag.colour, contents.join(", "))
+}
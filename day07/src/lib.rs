@@ -0,0 +1,207 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Error, Part, Result, Solution};
+
+mod generate;
+
+/// Counts the bags inside a `start` bag, or returns `None` if there are too
+/// many to count.
+///
+/// The rules must not put a bag inside itself. Each bag is counted once,
+/// after the bags inside it, keeping the bags still to count on a stack
+/// rather than recursing, so a long chain of rules cannot overflow it.
+fn count_bags(bag_list: &HashMap<String, Vec<(u32, String)>>, start: &str) -> Option<u32> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    let mut stack = vec![start];
+
+    while let Some(colour) = stack.last().copied() {
+        let rules = bag_list.get(colour).map_or(&[][..], Vec::as_slice);
+        if let Some((_, inner)) = rules
+            .iter()
+            .find(|(_, inner)| !counts.contains_key(inner.as_str()))
+        {
+            stack.push(inner);
+            continue;
+        }
+
+        let mut total: u32 = 0;
+        for (count, inner) in rules {
+            let inside = count.checked_mul(counts[inner.as_str()])?;
+            total = total.checked_add(*count)?.checked_add(inside)?;
+        }
+        counts.insert(colour, total);
+        stack.pop();
+    }
+
+    counts.get(start).copied()
+}
+
+/// Finds a bag that has to go inside itself, following the rules from
+/// `colour`. `open` holds the bags being followed, and `done` those known to
+/// lead to no such bag.
+///
+/// The bags being followed are kept on a stack, each with the index of the
+/// next rule to follow from it, rather than recursing.
+fn find_cycle<'a>(
+    bag_list: &'a HashMap<String, Vec<(u32, String)>>,
+    colour: &'a str,
+    open: &mut HashSet<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+    if done.contains(colour) {
+        return None;
+    }
+    open.insert(colour);
+    let mut stack = vec![(colour, 0)];
+
+    while let Some(top) = stack.last_mut() {
+        let (current, next) = *top;
+        top.1 += 1;
+
+        match bag_list.get(current).and_then(|rules| rules.get(next)) {
+            Some((_, inner)) => {
+                if done.contains(inner.as_str()) {
+                    continue;
+                }
+                if !open.insert(inner) {
+                    return Some(inner);
+                }
+                stack.push((inner, 0));
+            }
+            None => {
+                stack.pop();
+                open.remove(current);
+                done.insert(current);
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Rules {
+    contained_by: HashMap<String, HashSet<String>>,
+    container_tree: HashMap<String, Vec<(u32, String)>>,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Rules;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let mut contained_by: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut container_tree: HashMap<String, Vec<(u32, String)>> = HashMap::new();
+        let mut rule_lines = Vec::new();
+
+        for (n, line) in lines.iter().enumerate() {
+            let bad = |field: &str, cause: &str| {
+                Error::bad_input(cause)
+                    .line(n + 1)
+                    .column(aoc_common::column(line, field))
+            };
+
+            let (subject, contents) = line
+                .split_once(" bags contain ")
+                .ok_or_else(|| bad(line, "expected \"<colour> bags contain ...\""))?;
+            let subject = subject.to_string();
+
+            if contents == "no other bags." {
+                continue;
+            }
+
+            let mut contained = Vec::new();
+
+            let contents = contents
+                .strip_suffix('.')
+                .ok_or_else(|| bad(contents, "expected a full stop"))?;
+            for consist in contents.split(", ") {
+                let (count, containee) = consist
+                    .rsplit_once(' ')
+                    .and_then(|(consist, _)| consist.split_once(' '))
+                    .ok_or_else(|| bad(consist, "expected \"<count> <colour> bags\""))?;
+                let count: u32 = aoc_common::parse_field(line, count).map_err(|e| e.line(n + 1))?;
+                let containee = containee.to_string();
+
+                if let Some(set) = contained_by.get_mut(&containee) {
+                    set.insert(subject.clone());
+                } else {
+                    let mut set = HashSet::new();
+                    set.insert(subject.clone());
+                    contained_by.insert(containee.clone(), set);
+                }
+
+                contained.push((count, containee));
+            }
+
+            rule_lines.push((subject.clone(), n + 1));
+            container_tree.insert(subject, contained);
+        }
+
+        let (mut open, mut done) = (HashSet::new(), HashSet::new());
+        for (subject, _) in &rule_lines {
+            if let Some(colour) = find_cycle(&container_tree, subject, &mut open, &mut done) {
+                let error = Error::bad_input(format!("{} bags end up inside themselves", colour));
+                return Err(
+                    match rule_lines.iter().find(|(subject, _)| subject == colour) {
+                        Some((_, line)) => error.line(*line),
+                        None => error,
+                    },
+                );
+            }
+        }
+
+        Ok(Rules {
+            contained_by,
+            container_tree,
+        })
+    }
+
+    fn part1(&self, rules: &Self::Input) -> Result<Self::Part1> {
+        let mut iter_stack = Vec::new();
+        let mut container_set = HashSet::new();
+        if let Some(l) = rules.contained_by.get("shiny gold") {
+            iter_stack.push(l.iter());
+        }
+
+        while !iter_stack.is_empty() {
+            match iter_stack[0].next() {
+                Some(c) => {
+                    if !container_set.insert(c) {
+                        continue;
+                    }
+                    if let Some(l) = rules.contained_by.get(c) {
+                        iter_stack.push(l.iter());
+                    }
+                }
+                None => {
+                    iter_stack.remove(0);
+                    continue;
+                }
+            };
+        }
+
+        Ok(container_set.len())
+    }
+
+    fn part2(&self, rules: &Self::Input) -> Result<Self::Part2> {
+        count_bags(&rules.container_tree, "shiny gold")
+            .ok_or_else(|| Error::too_large("the number of bags"))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let rules = Day7.parse(lines)?;
+
+    if part.one() {
+        println!("{} candidates", Day7.part1(&rules)?);
+    }
+
+    if part.two() {
+        println!("{} bags", Day7.part2(&rules)?);
+    }
+
+    Ok(())
+}
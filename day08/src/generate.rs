@@ -0,0 +1,73 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::Day8;
+
+/// Writes a program that runs forwards, skipping over dead code, until a
+/// single backwards `jmp` sends it round again. Changing that `jmp` lets it
+/// run on through a tail that leaves the program.
+///
+/// Every other change must not let the program finish, so each dead region
+/// starts with `jmp +0` and every `nop` on the path points back at code
+/// that has already run.
+impl Generator for Day8 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let size = size.max(3);
+        let mut program = Vec::new();
+        let mut path = Vec::new();
+        let mut acc = 0;
+
+        while program.len() < size * 2 / 3 || path.is_empty() {
+            let at = program.len();
+            match rng.index(4) {
+                0 if !path.is_empty() => {
+                    let skip = rng.range(1..4) as usize;
+                    program.push(format!("jmp +{}", skip + 1));
+                    program.push(String::from("jmp +0"));
+                    program.extend((1..skip).map(|_| junk(rng)));
+                }
+                1 if !path.is_empty() => {
+                    let target = *rng.choose(&path) as i32;
+                    program.push(format!("nop {:+}", target - at as i32));
+                }
+                _ => {
+                    let value = rng.range(0..100) as i32 - 50;
+                    acc += value;
+                    program.push(format!("acc {:+}", value));
+                }
+            }
+            path.push(at);
+        }
+
+        let tweakpoint = program.len();
+        let target = *rng.choose(&path) as i32;
+        program.push(format!("jmp {:+}", target - tweakpoint as i32));
+        let looped = acc;
+
+        while program.len() < size {
+            if rng.chance(1, 4) {
+                program.push(String::from("nop +0"));
+            } else {
+                let value = rng.range(0..100) as i32 - 50;
+                acc += value;
+                program.push(format!("acc {:+}", value));
+            }
+        }
+
+        Generated {
+            lines: program,
+            answers: Answers {
+                part1: Some(looped.to_string()),
+                part2: Some(acc.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        600
+    }
+}
+
+fn junk(rng: &mut Rng) -> String {
+    let operation = rng.choose(&["acc", "jmp", "nop"]);
+    format!("{} {:+}", operation, rng.range(0..20) as i32 - 10)
+}
@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{Answer, Error, Part, Result, Solution};
+
+mod generate;
+
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    Accumulate(i32),
+    Jump(i32),
+    Noop(i32),
+}
+
+impl Instruction {
+    fn is_jump_or_noop(&self) -> bool {
+        match self {
+            Instruction::Accumulate(_) => false,
+            Instruction::Jump(_) => true,
+            Instruction::Noop(_) => true,
+        }
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = Error;
+
+    fn try_from(line: &str) -> Result<Self> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() != 2 {
+            return Err(Error::bad_input("expected an operation and an argument"));
+        }
+
+        let value = aoc_common::parse_field(line, parts[1])?;
+        match parts[0] {
+            "acc" => Ok(Self::Accumulate(value)),
+            "jmp" => Ok(Self::Jump(value)),
+            "nop" => Ok(Self::Noop(value)),
+            op => Err(Error::bad_input(format!("unknown operation {:?}", op))
+                .column(aoc_common::column(line, op))),
+        }
+    }
+}
+
+struct Executor {
+    program: Vec<Instruction>,
+    pc: usize,
+    accumulator: i32,
+}
+
+impl Executor {
+    fn new(program: Vec<Instruction>) -> Self {
+        Self {
+            program,
+            pc: 0,
+            accumulator: 0,
+        }
+    }
+
+    /// Carries out `instruction`, or fails if the accumulator would go
+    /// beyond what it can hold.
+    fn execute_one(&mut self, instruction: &Instruction) -> Result<()> {
+        match instruction {
+            Instruction::Accumulate(value) => {
+                self.accumulator = self
+                    .accumulator
+                    .checked_add(*value)
+                    .ok_or_else(|| Error::too_large("the accumulator").line(self.pc + 1))?;
+                self.pc += 1;
+            }
+            Instruction::Jump(value) => {
+                // A jump before the start leaves the program, as one past
+                // the end does.
+                let target = self.pc as i64 + i64::from(*value);
+                self.pc = usize::try_from(target).unwrap_or(usize::MAX);
+            }
+            Instruction::Noop(_) => {
+                self.pc += 1;
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Runs the program until an instruction comes round again, giving
+    /// the accumulator then, or `None` if the program leaves first.
+    fn run(&mut self) -> Result<Option<i32>> {
+        self.pc = 0;
+        self.accumulator = 0;
+
+        let mut visited = HashSet::new();
+
+        loop {
+            if visited.contains(&self.pc) {
+                return Ok(Some(self.accumulator));
+            } else {
+                visited.insert(self.pc);
+            }
+
+            let instruction = match self.program.get(self.pc) {
+                Some(instruction) => instruction.clone(),
+                None => return Ok(None),
+            };
+            self.execute_one(&instruction)?;
+        }
+    }
+
+    fn tweakpoints(&self) -> Vec<usize> {
+        self.program
+            .iter()
+            .enumerate()
+            .filter(|(_, insn)| insn.is_jump_or_noop())
+            .map(|(pc, _)| pc)
+            .collect()
+    }
+
+    /// Runs the program with the instruction at `tweakpoint` swapped,
+    /// giving the accumulator if it then ends just past the last
+    /// instruction.
+    fn run_tweaked(&mut self, tweakpoint: usize) -> Result<Option<i32>> {
+        self.pc = 0;
+        self.accumulator = 0;
+
+        let mut visited = HashSet::new();
+
+        loop {
+            if visited.contains(&self.pc) {
+                return Ok(None);
+            } else {
+                visited.insert(self.pc);
+            }
+
+            let mut instruction = match self.program.get(self.pc) {
+                Some(instruction) => instruction.clone(),
+                None => return Ok(None),
+            };
+            if self.pc == tweakpoint {
+                instruction = match instruction {
+                    Instruction::Jump(value) => Instruction::Noop(value),
+                    Instruction::Noop(value) => Instruction::Jump(value),
+                    i => i,
+                };
+            }
+
+            self.execute_one(&instruction)?;
+
+            match self.pc.cmp(&self.program.len()) {
+                Ordering::Equal => return Ok(Some(self.accumulator)),
+                Ordering::Greater => return Ok(None),
+                Ordering::Less => (),
+            };
+        }
+    }
+}
+
+pub struct Repair {
+    pub tweakpoint: usize,
+    pub acc: i32,
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.acc)
+    }
+}
+
+impl Answer for Repair {
+    fn diagnostics(&self) -> Option<Value> {
+        Some(json!({ "tweakpoint": self.tweakpoint }))
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = Repair;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| Instruction::try_from(line))
+    }
+
+    fn part1(&self, program: &Self::Input) -> Result<Self::Part1> {
+        let mut executor = Executor::new(program.clone());
+        executor
+            .run()?
+            .ok_or_else(|| Error::no_solution("the program leaves without looping"))
+    }
+
+    fn part2(&self, program: &Self::Input) -> Result<Self::Part2> {
+        let mut executor = Executor::new(program.clone());
+        // A tweak whose run overflows the accumulator is not the repair, but
+        // another tweak may still be, so the error is only reported if none
+        // is.
+        let mut overflow = None;
+        for tweakpoint in executor.tweakpoints() {
+            match executor.run_tweaked(tweakpoint) {
+                Ok(Some(acc)) => return Ok(Repair { tweakpoint, acc }),
+                Ok(None) => (),
+                Err(error) => {
+                    overflow.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(overflow.unwrap_or_else(|| Error::no_solution("no tweak makes the program terminate")))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let program = Day8.parse(lines)?;
+
+    if part.one() {
+        println!("acc = {}", Day8.part1(&program)?);
+    }
+
+    if part.two() {
+        let repair = Day8.part2(&program)?;
+        println!("tweakpoint = {}, acc = {}", repair.tweakpoint, repair.acc);
+    }
+
+    Ok(())
+}
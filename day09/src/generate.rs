@@ -0,0 +1,95 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::{Day9, Weakness};
+
+/// The largest number generated, far enough below `usize::MAX` that any
+/// two add up.
+const LIMIT: usize = usize::MAX >> 16;
+
+/// Plants the invalid number as the sum of the first few numbers, which
+/// makes them the first run the search finds, then places it where it is
+/// not the sum of any pair in the numbers before it.
+///
+/// Every other number up to the invalid one is the sum of two of the few
+/// smallest numbers before it. Those sums still double every few dozen
+/// numbers, so the invalid number goes in early if they would pass `LIMIT`,
+/// and after it, where neither part looks, the numbers start again from
+/// small ones whenever they would.
+impl Generator for Day9 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let preamble = self.preamble;
+        let size = size.max(preamble + 2);
+        let mut numbers: Vec<usize> = (0..preamble).map(|_| rng.range(1..100) as usize).collect();
+
+        if preamble < 2 {
+            // No number can be the sum of a pair, so the first one after the
+            // preamble is invalid and there is no weakness to plant.
+            numbers.extend((preamble..size).map(|_| rng.range(1..100) as usize));
+            return Generated {
+                answers: Answers {
+                    part1: Some(numbers[preamble].to_string()),
+                    part2: None,
+                },
+                lines: numbers.iter().map(|n| n.to_string()).collect(),
+            };
+        }
+
+        loop {
+            let len = rng.range(2..preamble as u64 + 1) as usize;
+            let invalid: usize = numbers[..len].iter().sum();
+            let at = rng.range(preamble as u64..size as u64 - 1) as usize;
+            let mut numbers = numbers.clone();
+            let mut placed = None;
+
+            while numbers.len() < size {
+                let window = &numbers[numbers.len() - preamble..];
+                if placed.is_none() && numbers.len() >= at && !is_pair_sum(window, invalid) {
+                    placed = Some(numbers.len());
+                    numbers.push(invalid);
+                    continue;
+                }
+
+                let mut smallest = window.to_vec();
+                smallest.sort_unstable();
+                smallest.truncate(5);
+                let i = rng.index(smallest.len());
+                let j = (i + 1 + rng.index(smallest.len() - 1)) % smallest.len();
+                let sum = smallest[i] + smallest[j];
+
+                if sum <= LIMIT {
+                    numbers.push(sum);
+                } else if placed.is_some() {
+                    numbers.push(rng.range(1..100) as usize);
+                } else if !is_pair_sum(window, invalid) {
+                    placed = Some(numbers.len());
+                    numbers.push(invalid);
+                } else {
+                    break;
+                }
+            }
+
+            // The weakness must also be followed by another number, as the
+            // search only checks a run once the next number arrives.
+            if placed.is_some_and(|at| at + 1 < size) {
+                return Generated {
+                    answers: Answers {
+                        part1: Some(invalid.to_string()),
+                        part2: Some(Weakness(numbers[..len].to_vec()).to_string()),
+                    },
+                    lines: numbers.iter().map(|n| n.to_string()).collect(),
+                };
+            }
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        1000
+    }
+}
+
+fn is_pair_sum(window: &[usize], number: usize) -> bool {
+    window
+        .iter()
+        .enumerate()
+        .any(|(i, x)| window[i + 1..].iter().any(|y| x + y == number))
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{Answer, Error, Part, Result, Solution};
+use itertools::Itertools;
+
+mod generate;
+
+pub const PREAMBLE: usize = 25;
+
+struct NumberBuffer {
+    buffer: Vec<usize>,
+    preamble: usize,
+}
+
+impl NumberBuffer {
+    fn new(preamble: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            preamble,
+        }
+    }
+
+    fn push(&mut self, number: usize) {
+        self.buffer.push(number);
+        if self.buffer.len() > self.preamble {
+            self.buffer.remove(0);
+        }
+    }
+
+    fn ready(&self) -> bool {
+        self.buffer.len() >= self.preamble
+    }
+
+    fn is_sum_of_preamble_pair(&self, number: usize) -> bool {
+        for pair in self.buffer.iter().combinations(2) {
+            // A pair too large to add up cannot sum to any number.
+            if pair[0].checked_add(*pair[1]) == Some(number) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// The sum of `run`, or `None` if it is too large to hold, and so larger
+/// than any number.
+fn run_sum(run: &[usize]) -> Option<usize> {
+    run.iter().try_fold(0usize, |sum, n| sum.checked_add(*n))
+}
+
+pub struct Weakness(pub Vec<usize>);
+
+impl Weakness {
+    pub fn value(&self) -> usize {
+        self.0.iter().min().unwrap() + self.0.iter().max().unwrap()
+    }
+}
+
+impl fmt::Display for Weakness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl Answer for Weakness {
+    fn diagnostics(&self) -> Option<Value> {
+        Some(json!({ "run": self.0 }))
+    }
+}
+
+pub struct Day9 {
+    pub preamble: usize,
+}
+
+impl Default for Day9 {
+    fn default() -> Self {
+        Self { preamble: PREAMBLE }
+    }
+}
+
+impl Solution for Day9 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = Weakness;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| aoc_common::parse_field(line, line))
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Self::Part1> {
+        let mut buffer = NumberBuffer::new(self.preamble);
+
+        for number in numbers.iter().copied() {
+            if buffer.ready() && !buffer.is_sum_of_preamble_pair(number) {
+                return Ok(number);
+            }
+
+            buffer.push(number);
+        }
+
+        Err(Error::no_solution(
+            "every number is the sum of a preamble pair",
+        ))
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Self::Part2> {
+        let magic_number = self.part1(numbers)?;
+        let mut run: Vec<usize> = Vec::new();
+
+        for number in numbers.iter().copied() {
+            while !matches!(run_sum(&run), Some(sum) if sum <= magic_number) {
+                run.remove(0);
+            }
+
+            if run_sum(&run) == Some(magic_number) && run.len() > 1 {
+                return Ok(Weakness(run));
+            }
+
+            run.push(number);
+        }
+
+        Err(Error::no_solution(format!(
+            "no run of numbers sums to {}",
+            magic_number
+        )))
+    }
+}
+
+pub fn run(lines: &[String], part: Part, preamble: usize) -> Result<()> {
+    let day = Day9 { preamble };
+    let numbers = day.parse(lines)?;
+
+    if part.one() {
+        println!("{}", day.part1(&numbers)?);
+    }
+
+    if part.two() {
+        let weakness = day.part2(&numbers)?;
+        println!("{:?} {}", weakness.0, weakness.value());
+    }
+
+    Ok(())
+}
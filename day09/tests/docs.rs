@@ -0,0 +1,36 @@
+use std::process::Command;
+
+fn day9(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_day9"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "day9 {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn documentation_needs_no_preamble() {
+    for shell in &["bash", "zsh", "fish"] {
+        assert!(
+            day9(&["completions", shell]).contains("preamble"),
+            "{}",
+            shell
+        );
+    }
+
+    let page = day9(&["man"]);
+    assert!(page.starts_with(".TH \"DAY9\""));
+    assert!(page.contains("-p, --preamble <PREAMBLE>"));
+}
+
+#[test]
+fn inputs_named_like_the_subcommands_are_still_inputs() {
+    let output = Command::new(env!("CARGO_BIN_EXE_day9"))
+        .args(["--preamble", "5", "man"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(74));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: man: "));
+}
@@ -0,0 +1,65 @@
+use aoc_common::differential::{removals, Differential};
+use aoc_common::{Rng, Solution};
+
+use crate::Day10;
+
+/// Counts arrangements by trying every way of leaving adapters out, which
+/// is slow but follows the puzzle text directly.
+fn every_arrangement(numbers: &[usize], from: usize) -> usize {
+    match numbers.split_first() {
+        None => 1,
+        Some((first, _)) if first - from > 3 => 0,
+        Some((first, rest)) => {
+            let using = every_arrangement(rest, *first);
+            // The last adapter is always needed to reach the device.
+            let skipping = if rest.is_empty() {
+                0
+            } else {
+                every_arrangement(rest, from)
+            };
+            using + skipping
+        }
+    }
+}
+
+/// Compares part 2 against an exhaustive count of arrangements, on chains
+/// with any mix of one, two and three jolt steps.
+pub struct ArrangementCount;
+
+impl Differential for ArrangementCount {
+    const NAME: &'static str = "day 10: arrangements vs exhaustive";
+    const LEFT: &'static str = "part 2";
+    const RIGHT: &'static str = "exhaustive";
+
+    type Input = Vec<usize>;
+    type Output = Option<usize>;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Input {
+        let mut joltage = 0;
+        (0..size)
+            .map(|_| {
+                joltage += rng.range(1..4) as usize;
+                joltage
+            })
+            .collect()
+    }
+
+    fn shrink(&self, adapters: &Self::Input) -> Vec<Self::Input> {
+        removals(adapters)
+    }
+
+    fn left(&self, adapters: &Self::Input) -> Self::Output {
+        let lines: Vec<String> = adapters.iter().map(|a| a.to_string()).collect();
+        let numbers = Day10.parse(&lines).ok()?;
+        Day10.part2(&numbers).ok()
+    }
+
+    fn right(&self, adapters: &Self::Input) -> Self::Output {
+        let mut numbers = adapters.clone();
+        numbers.sort_unstable();
+        match every_arrangement(&numbers, 0) {
+            0 => None,
+            count => Some(count),
+        }
+    }
+}
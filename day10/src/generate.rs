@@ -0,0 +1,55 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::{Day10, Differences};
+
+/// The ways to arrange a run of one-jolt steps between two fixed adapters,
+/// for runs of up to four steps.
+const ARRANGEMENTS: [usize; 5] = [1, 1, 2, 4, 7];
+
+/// Chains adapters in runs of one-jolt steps separated by three-jolt steps.
+///
+/// As in the real inputs, there are no two-jolt steps and no run is longer
+/// than four, and the runs stop growing once the number of arrangements
+/// would no longer fit in a `usize`.
+impl Generator for Day10 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let mut adapters = Vec::new();
+        let mut joltage = 0;
+        let mut ones = 0;
+        // The device is always three jolts above the last adapter.
+        let mut threes = 1;
+        let mut arrangements: usize = 1;
+
+        while adapters.len() < size.max(1) {
+            let mut run = rng.index(ARRANGEMENTS.len());
+            while arrangements.checked_mul(ARRANGEMENTS[run]).is_none() {
+                run -= 1;
+            }
+            arrangements *= ARRANGEMENTS[run];
+
+            for _ in 0..run {
+                joltage += 1;
+                ones += 1;
+                adapters.push(joltage);
+            }
+
+            joltage += 3;
+            threes += 1;
+            adapters.push(joltage);
+        }
+
+        rng.shuffle(&mut adapters);
+
+        Generated {
+            lines: adapters.iter().map(|a| a.to_string()).collect(),
+            answers: Answers {
+                part1: Some(Differences { ones, threes }.to_string()),
+                part2: Some(arrangements.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        100
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::iter;
+
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{Answer, Error, Part, Result, Solution};
+
+pub mod differential;
+mod generate;
+
+fn pairwise<T>(iter: T) -> impl Iterator<Item = (usize, usize)>
+where
+    T: Iterator<Item = usize> + Clone,
+{
+    let iter2 = iter::once(0).chain(iter.clone());
+    iter2.zip(iter)
+}
+
+fn check_chain(numbers: &[usize]) -> Result<()> {
+    match pairwise(numbers.iter().copied()).find(|(a, b)| !(1..=3).contains(&(b - a))) {
+        Some((a, b)) => Err(Error::no_solution(format!(
+            "no adapter fits between {} and {} jolts",
+            a, b
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Counts the ways to chain from the outlet to the last adapter, building
+/// up the count for each adapter from those up to three jolts below it, or
+/// returns `None` if there are too many ways to count.
+fn arrangements(numbers: &[usize]) -> Option<usize> {
+    let mut ways = vec![0usize; numbers.len()];
+
+    for (i, n) in numbers.iter().enumerate() {
+        let from_outlet = usize::from(*n <= 3);
+        ways[i] = (0..i)
+            .rev()
+            .take_while(|j| n - numbers[*j] <= 3)
+            .try_fold(from_outlet, |sum, j| sum.checked_add(ways[j]))?;
+    }
+
+    Some(ways.last().copied().unwrap_or(1))
+}
+
+pub struct Differences {
+    pub ones: usize,
+    pub threes: usize,
+}
+
+impl fmt::Display for Differences {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ones * self.threes)
+    }
+}
+
+impl Answer for Differences {
+    fn diagnostics(&self) -> Option<Value> {
+        Some(json!({ "ones": self.ones, "threes": self.threes }))
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<usize>;
+    type Part1 = Differences;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let mut numbers: Vec<usize> =
+            aoc_common::parse_lines(lines, |line| aoc_common::parse_field(line, line))?;
+
+        numbers.sort_unstable();
+
+        Ok(numbers)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Self::Part1> {
+        check_chain(numbers)?;
+
+        let mut count_1 = 0;
+        let mut count_3 = 1;
+
+        for difference in pairwise(numbers.iter().copied()).map(|(a, b)| b - a) {
+            match difference {
+                1 => count_1 += 1,
+                3 => count_3 += 1,
+                _ => (),
+            };
+        }
+
+        Ok(Differences {
+            ones: count_1,
+            threes: count_3,
+        })
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Self::Part2> {
+        check_chain(numbers)?;
+
+        arrangements(numbers).ok_or_else(|| Error::too_large("the number of arrangements"))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let numbers = Day10.parse(lines)?;
+
+    if part.one() {
+        let differences = Day10.part1(&numbers)?;
+        println!(
+            "{} x 1, {} x 3 -> {}",
+            differences.ones, differences.threes, differences
+        );
+    }
+
+    if part.two() {
+        println!("{}", Day10.part2(&numbers)?);
+    }
+
+    Ok(())
+}
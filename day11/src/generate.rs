@@ -0,0 +1,88 @@
+use aoc_common::{Generated, Generator, Grid, Rng};
+
+use crate::{Day11, SeatState, SeatingArea};
+
+/// How many rounds a layout may take to settle before it is assumed to
+/// be stuck in a loop.
+const MAX_ROUNDS: usize = 1000;
+
+/// Lays out rows of seats with patches of floor. The settled layout
+/// depends on the whole room, so no answers are planted.
+///
+/// Not every random room settles: some patches of seats flip back and
+/// forth forever, and the solver would never finish. Those seats are
+/// turned into floor a few at a time until the room settles under both
+/// sets of rules.
+impl Generator for Day11 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let width = 90;
+        let height = size.max(1);
+
+        let mut floor = Grid::new(width, height, SeatState::Empty);
+        for y in 0..height {
+            for x in 0..width {
+                if rng.chance(1, 6) {
+                    floor[(x, y)] = SeatState::Floor;
+                }
+            }
+        }
+        let mut area = SeatingArea { floor };
+
+        // Fixing one set of rules can unsettle the other, so go round
+        // until neither needs fixing.
+        let mut fixing = true;
+        while fixing {
+            fixing = false;
+            for cycle in [SeatingArea::run_cycle_v1, SeatingArea::run_cycle_v2] {
+                while let Some(flipping) = unsettled(&area, cycle) {
+                    fixing = true;
+                    for at in flipping {
+                        if rng.chance(1, 4) {
+                            area.floor[at] = SeatState::Floor;
+                        }
+                    }
+                }
+            }
+        }
+
+        Generated {
+            lines: area.to_string().lines().map(String::from).collect(),
+            ..Generated::default()
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        90
+    }
+}
+
+/// Runs `area` until it settles, returning `None` if it does, or the seats
+/// that are still changing if it does not.
+fn unsettled(
+    area: &SeatingArea,
+    cycle: fn(&SeatingArea) -> SeatingArea,
+) -> Option<Vec<(usize, usize)>> {
+    let mut previous = area.clone();
+    let mut current = cycle(area);
+
+    for _ in 0..MAX_ROUNDS {
+        let next = cycle(&current);
+        if next == current {
+            return None;
+        }
+        if next == previous {
+            break;
+        }
+        previous = current;
+        current = next;
+    }
+
+    Some(
+        current
+            .floor
+            .positions()
+            .filter(|(at, state)| previous.floor[*at] != **state)
+            .map(|(at, _)| at)
+            .collect(),
+    )
+}
@@ -0,0 +1,224 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use aoc_common::{trace, Answer, Error, Frame, Grid, Part, Result, Solution, DIRECTIONS8};
+
+mod generate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeatState {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl fmt::Display for SeatState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let character = match self {
+            Self::Floor => '.',
+            Self::Empty => 'L',
+            Self::Occupied => '#',
+        };
+
+        write!(f, "{}", character)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeatingArea {
+    floor: Grid<SeatState>,
+}
+
+impl TryFrom<&[String]> for SeatingArea {
+    type Error = Error;
+
+    fn try_from(lines: &[String]) -> Result<Self> {
+        let floor = Grid::parse(lines, |character| match character {
+            '.' => Some(SeatState::Floor),
+            'L' => Some(SeatState::Empty),
+            _ => None,
+        })?;
+
+        Ok(Self { floor })
+    }
+}
+
+impl fmt::Display for SeatingArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.floor)
+    }
+}
+
+impl SeatingArea {
+    fn occupied_neighbours(&self, at: (usize, usize)) -> usize {
+        self.floor
+            .neighbours8(at)
+            .filter(|n| self.floor[*n] == SeatState::Occupied)
+            .count()
+    }
+
+    fn visibly_occupied_neighbours(&self, at: (usize, usize)) -> usize {
+        DIRECTIONS8
+            .iter()
+            .filter(|direction| {
+                self.floor
+                    .ray(at, **direction)
+                    .map(|(_, state)| *state)
+                    .find(|state| *state != SeatState::Floor)
+                    == Some(SeatState::Occupied)
+            })
+            .count()
+    }
+
+    fn run_cycle_v1(&self) -> Self {
+        let floor = self.floor.map(|at, state| match state {
+            SeatState::Floor => SeatState::Floor,
+            SeatState::Empty => {
+                if self.occupied_neighbours(at) == 0 {
+                    SeatState::Occupied
+                } else {
+                    SeatState::Empty
+                }
+            }
+            SeatState::Occupied => {
+                if self.occupied_neighbours(at) >= 4 {
+                    SeatState::Empty
+                } else {
+                    SeatState::Occupied
+                }
+            }
+        });
+
+        Self { floor }
+    }
+
+    fn run_cycle_v2(&self) -> Self {
+        let floor = self.floor.map(|at, state| match state {
+            SeatState::Floor => SeatState::Floor,
+            SeatState::Empty => {
+                if self.visibly_occupied_neighbours(at) == 0 {
+                    SeatState::Occupied
+                } else {
+                    SeatState::Empty
+                }
+            }
+            SeatState::Occupied => {
+                if self.visibly_occupied_neighbours(at) >= 5 {
+                    SeatState::Empty
+                } else {
+                    SeatState::Occupied
+                }
+            }
+        });
+
+        Self { floor }
+    }
+
+    /// Runs `cycle` until nothing changes, showing `observe` the seating
+    /// after each round that changed it.
+    fn settle(&self, cycle: fn(&Self) -> Self, mut observe: impl FnMut(usize, &Self)) -> Self {
+        let mut f1 = self.clone();
+        let mut f2 = cycle(self);
+        let mut round = 1;
+
+        while f1 != f2 {
+            observe(round, &f2);
+            round += 1;
+
+            f1 = f2;
+            f2 = cycle(&f1);
+        }
+
+        f2
+    }
+
+    pub fn occupied(&self) -> usize {
+        self.floor
+            .cells()
+            .filter(|state| **state == SeatState::Occupied)
+            .count()
+    }
+}
+
+fn trace_round(round: usize, floor: &SeatingArea) {
+    trace!("Round {}:", round);
+    trace!("{}", floor);
+}
+
+pub struct Settled(pub SeatingArea);
+
+impl fmt::Display for Settled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.occupied())
+    }
+}
+
+impl Answer for Settled {
+    fn visualisation(&self) -> Option<String> {
+        Some(self.0.to_string())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = SeatingArea;
+    type Part1 = Settled;
+    type Part2 = Settled;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        SeatingArea::try_from(lines)
+    }
+
+    fn part1(&self, floor: &Self::Input) -> Result<Self::Part1> {
+        Ok(Settled(
+            floor.settle(SeatingArea::run_cycle_v1, trace_round),
+        ))
+    }
+
+    fn part2(&self, floor: &Self::Input) -> Result<Self::Part2> {
+        Ok(Settled(
+            floor.settle(SeatingArea::run_cycle_v2, trace_round),
+        ))
+    }
+}
+
+/// The seating area as it starts and after each round of `part` that
+/// changes it.
+pub fn frames(lines: &[String], part: Part) -> Result<Vec<Frame>> {
+    let floor = Day11.parse(lines)?;
+    let cycle = match part {
+        Part::Two => SeatingArea::run_cycle_v2,
+        _ => SeatingArea::run_cycle_v1,
+    };
+
+    let mut frames = vec![Frame::new("Start", &floor)];
+    floor.settle(cycle, |round, floor| {
+        frames.push(Frame::new(format!("Round {}", round), floor))
+    });
+
+    Ok(frames)
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let floor = Day11.parse(lines)?;
+    println!("{}", floor);
+
+    if part.one() {
+        let settled = Day11.part1(&floor)?;
+        println!("{}", settled.0);
+        println!("{} occupied", settled);
+    }
+
+    if part == Part::Both {
+        println!();
+    }
+
+    if part.two() {
+        let settled = Day11.part2(&floor)?;
+        println!("{}", settled.0);
+        println!("{} occupied", settled);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Generated, Generator, Rng};
+
+use crate::Day12;
+
+/// Writes navigation instructions in the proportions of a real input. The
+/// distances depend on replaying every instruction, so no answers are
+/// planted.
+///
+/// Moves are kept short, so the waypoint wanders slowly and distances stay
+/// well inside an `i32` even for long inputs.
+impl Generator for Day12 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        Generated {
+            lines: (0..size)
+                .map(|_| match rng.index(8) {
+                    0 | 1 => format!("F{}", rng.range(1..101)),
+                    2 => format!("{}{}", rng.choose(&['L', 'R']), rng.choose(&[90, 180, 270])),
+                    _ => format!("{}{}", rng.choose(&['N', 'S', 'E', 'W']), rng.range(1..6)),
+                })
+                .collect(),
+            ..Generated::default()
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        780
+    }
+}
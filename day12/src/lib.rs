@@ -0,0 +1,304 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use aoc_common::{Error, Part, Result, Solution};
+
+mod generate;
+
+/// How far the ferry or its waypoint turns, which is only ever a whole
+/// number of quarter turns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    Quarter,
+    Half,
+    ThreeQuarters,
+}
+
+impl Rotation {
+    fn from_degrees(degrees: usize) -> Option<Self> {
+        match degrees {
+            90 => Some(Self::Quarter),
+            180 => Some(Self::Half),
+            270 => Some(Self::ThreeQuarters),
+            _ => None,
+        }
+    }
+
+    fn quarters(self) -> usize {
+        match self {
+            Self::Quarter => 1,
+            Self::Half => 2,
+            Self::ThreeQuarters => 3,
+        }
+    }
+
+    /// The same turn the other way round, as a turn this way.
+    fn reversed(self) -> Self {
+        match self {
+            Self::Quarter => Self::ThreeQuarters,
+            Self::Half => Self::Half,
+            Self::ThreeQuarters => Self::Quarter,
+        }
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.quarters() * 90)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Action {
+    North(usize),
+    South(usize),
+    East(usize),
+    West(usize),
+    Left(Rotation),
+    Right(Rotation),
+    Forward(usize),
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::North(v) => write!(f, "N{}", v),
+            Self::South(v) => write!(f, "S{}", v),
+            Self::East(v) => write!(f, "E{}", v),
+            Self::West(v) => write!(f, "W{}", v),
+            Self::Left(v) => write!(f, "L{}", v),
+            Self::Right(v) => write!(f, "R{}", v),
+            Self::Forward(v) => write!(f, "F{}", v),
+        }
+    }
+}
+
+impl TryFrom<&str> for Action {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        let action = s
+            .chars()
+            .next()
+            .ok_or_else(|| Error::bad_input("expected an action"))?;
+        let value = &s[action.len_utf8()..];
+        let value = aoc_common::parse_field(s, value)?;
+        let rotation = || {
+            Rotation::from_degrees(value).ok_or_else(|| {
+                Error::bad_input(format!("bad rotation {}", value)).column(action.len_utf8() + 1)
+            })
+        };
+
+        match action {
+            'N' => Ok(Self::North(value)),
+            'S' => Ok(Self::South(value)),
+            'E' => Ok(Self::East(value)),
+            'W' => Ok(Self::West(value)),
+            'L' => Ok(Self::Left(rotation()?)),
+            'R' => Ok(Self::Right(rotation()?)),
+            'F' => Ok(Self::Forward(value)),
+            _ => Err(Error::bad_input(format!("unknown action {:?}", action)).column(1)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    /// The heading after turning left by `rotation`.
+    fn turned_left(self, rotation: Rotation) -> Self {
+        let mut heading = self;
+        for _ in 0..rotation.quarters() {
+            heading = match heading {
+                Heading::North => Heading::West,
+                Heading::West => Heading::South,
+                Heading::South => Heading::East,
+                Heading::East => Heading::North,
+            };
+        }
+        heading
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::North => write!(f, "N"),
+            Self::South => write!(f, "S"),
+            Self::East => write!(f, "E"),
+            Self::West => write!(f, "W"),
+        }
+    }
+}
+
+/// A distance as a signed offset, or `None` if it is too large for one.
+fn offset(v: usize) -> Option<i64> {
+    i64::try_from(v).ok()
+}
+
+/// The Manhattan distance of a position from the start, or `None` if it is
+/// too large.
+fn manhattan(north: i64, east: i64) -> Option<usize> {
+    let distance = north.checked_abs()?.checked_add(east.checked_abs()?)?;
+    usize::try_from(distance).ok()
+}
+
+struct FerryV1 {
+    north: i64,
+    east: i64,
+    heading: Heading,
+}
+
+impl FerryV1 {
+    fn new() -> Self {
+        Self {
+            north: 0,
+            east: 0,
+            heading: Heading::East,
+        }
+    }
+
+    /// Carries out `action`, or returns `None` if the ferry goes further
+    /// than can be counted.
+    fn process_action(&mut self, action: Action) -> Option<()> {
+        match action {
+            Action::North(v) => self.north = self.north.checked_add(offset(v)?)?,
+            Action::South(v) => self.north = self.north.checked_sub(offset(v)?)?,
+            Action::East(v) => self.east = self.east.checked_add(offset(v)?)?,
+            Action::West(v) => self.east = self.east.checked_sub(offset(v)?)?,
+            Action::Left(rotation) => self.heading = self.heading.turned_left(rotation),
+            Action::Right(rotation) => self.heading = self.heading.turned_left(rotation.reversed()),
+            Action::Forward(v) => match self.heading {
+                Heading::North => self.north = self.north.checked_add(offset(v)?)?,
+                Heading::South => self.north = self.north.checked_sub(offset(v)?)?,
+                Heading::East => self.east = self.east.checked_add(offset(v)?)?,
+                Heading::West => self.east = self.east.checked_sub(offset(v)?)?,
+            },
+        }
+
+        Some(())
+    }
+
+    fn distance(&self) -> Option<usize> {
+        manhattan(self.north, self.east)
+    }
+}
+
+/// A waypoint `(north, east)` turned left about the ferry, or `None` if it
+/// ends up further away than can be counted.
+fn turn_left(waypoint: (i64, i64), rotation: Rotation) -> Option<(i64, i64)> {
+    let (mut north, mut east) = waypoint;
+    for _ in 0..rotation.quarters() {
+        (north, east) = (east, north.checked_neg()?);
+    }
+    Some((north, east))
+}
+
+struct FerryV2 {
+    north: i64,
+    east: i64,
+    waypoint: (i64, i64),
+}
+
+impl FerryV2 {
+    fn new() -> Self {
+        Self {
+            north: 0,
+            east: 0,
+            waypoint: (1, 10),
+        }
+    }
+
+    /// Carries out `action`, or returns `None` if the ferry or its waypoint
+    /// go further than can be counted.
+    fn process_action(&mut self, action: Action) -> Option<()> {
+        let (north, east) = self.waypoint;
+
+        match action {
+            Action::North(v) => self.waypoint = (north.checked_add(offset(v)?)?, east),
+            Action::South(v) => self.waypoint = (north.checked_sub(offset(v)?)?, east),
+            Action::East(v) => self.waypoint = (north, east.checked_add(offset(v)?)?),
+            Action::West(v) => self.waypoint = (north, east.checked_sub(offset(v)?)?),
+            Action::Left(rotation) => self.waypoint = turn_left((north, east), rotation)?,
+            Action::Right(rotation) => {
+                self.waypoint = turn_left((north, east), rotation.reversed())?
+            }
+            Action::Forward(v) => {
+                self.north = self.north.checked_add(north.checked_mul(offset(v)?)?)?;
+                self.east = self.east.checked_add(east.checked_mul(offset(v)?)?)?;
+            }
+        }
+
+        Some(())
+    }
+
+    fn distance(&self) -> Option<usize> {
+        manhattan(self.north, self.east)
+    }
+}
+
+/// The error for a ferry that has gone too far, after the action on `line`
+/// or, without one, by the end.
+fn too_far(line: Option<usize>) -> Error {
+    let error = Error::too_large("the distance the ferry goes");
+    match line {
+        Some(line) => error.line(line),
+        None => error,
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Action>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| Action::try_from(line))
+    }
+
+    fn part1(&self, actions: &Self::Input) -> Result<Self::Part1> {
+        let mut ferry = FerryV1::new();
+
+        for (n, action) in actions.iter().copied().enumerate() {
+            ferry
+                .process_action(action)
+                .ok_or_else(|| too_far(Some(n + 1)))?;
+        }
+
+        ferry.distance().ok_or_else(|| too_far(None))
+    }
+
+    fn part2(&self, actions: &Self::Input) -> Result<Self::Part2> {
+        let mut ferry = FerryV2::new();
+
+        for (n, action) in actions.iter().copied().enumerate() {
+            ferry
+                .process_action(action)
+                .ok_or_else(|| too_far(Some(n + 1)))?;
+        }
+
+        ferry.distance().ok_or_else(|| too_far(None))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let actions = Day12.parse(lines)?;
+
+    if part.one() {
+        println!("distance v1: {}", Day12.part1(&actions)?);
+    }
+
+    if part.two() {
+        println!("distance v2: {}", Day12.part2(&actions)?);
+    }
+
+    Ok(())
+}
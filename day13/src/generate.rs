@@ -0,0 +1,91 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::{Day13, Departure};
+
+/// Picks the answer to part 2 first, then places each bus where it leaves
+/// at the right offset from it.
+///
+/// The bus IDs are distinct primes, so the planted timestamp is the
+/// earliest one as long as it is below their product. Buses are added
+/// until that product would pass 2^63.
+impl Generator for Day13 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let mut primes = primes(7..1000);
+        rng.shuffle(&mut primes);
+
+        let mut busses = Vec::new();
+        let mut product: u64 = 1;
+        for prime in primes.into_iter().take(size.max(1)) {
+            match product.checked_mul(prime).filter(|p| *p <= 1 << 63) {
+                Some(p) => product = p,
+                None => break,
+            }
+            busses.push(prime);
+        }
+
+        let timestamp = rng.range(0..product);
+        let mut schedule: Vec<Option<u64>> = Vec::new();
+        for bus in busses.iter().copied() {
+            // A bus leaving i minutes after the timestamp needs i ≡ -t (mod bus).
+            let mut offset = (bus - timestamp % bus) % bus;
+            while schedule
+                .get(offset as usize)
+                .is_some_and(|slot| slot.is_some())
+            {
+                offset += bus;
+            }
+            if schedule.len() <= offset as usize {
+                schedule.resize(offset as usize + 1, None);
+            }
+            schedule[offset as usize] = Some(bus);
+        }
+
+        let (earliest, departure) = loop {
+            let earliest = rng.range(100_000..1_000_000);
+            let mut waits: Vec<(u64, u64)> = busses
+                .iter()
+                .map(|bus| (bus - earliest % bus, *bus))
+                .collect();
+            waits.sort_unstable();
+
+            // Avoid a bus leaving exactly at the earliest time, or two
+            // buses tying for the shortest wait.
+            if !earliest.is_multiple_of(waits[0].1)
+                && waits.get(1).is_none_or(|w| w.0 != waits[0].0)
+            {
+                let (wait_time, bus_id) = waits[0];
+                break (
+                    earliest,
+                    Departure {
+                        bus_id: bus_id as usize,
+                        wait_time: wait_time as usize,
+                        product: (bus_id * wait_time) as usize,
+                    },
+                );
+            }
+        };
+
+        let schedule: Vec<String> = schedule
+            .iter()
+            .map(|slot| slot.map_or_else(|| String::from("x"), |bus| bus.to_string()))
+            .collect();
+
+        Generated {
+            lines: vec![earliest.to_string(), schedule.join(",")],
+            answers: Answers {
+                part1: Some(departure.to_string()),
+                part2: Some(timestamp.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        9
+    }
+}
+
+fn primes(range: std::ops::Range<u64>) -> Vec<u64> {
+    range
+        .filter(|n| *n > 1 && (2..).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+        .collect()
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+
+use aoc_common::number_theory;
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{Answer, Error, Part, Result, Solution};
+
+mod generate;
+
+pub struct Notes {
+    earliest: usize,
+    busses: Vec<Option<usize>>,
+}
+
+pub struct Departure {
+    pub bus_id: usize,
+    pub wait_time: usize,
+    /// The bus ID times the wait, which is the answer.
+    product: usize,
+}
+
+impl fmt::Display for Departure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.product)
+    }
+}
+
+impl Answer for Departure {
+    fn diagnostics(&self) -> Option<Value> {
+        Some(json!({ "bus_id": self.bus_id, "wait_time": self.wait_time }))
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+    type Part1 = Departure;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        if lines.len() != 2 {
+            return Err(Error::bad_input("expected a timestamp line and a bus line"));
+        }
+
+        let earliest = aoc_common::parse_field(&lines[0], &lines[0]).map_err(|e| e.line(1))?;
+        let busses = lines[1]
+            .split(',')
+            .map(|v| match v {
+                "x" => Ok(None),
+                x => match aoc_common::parse_field(&lines[1], x)? {
+                    0 => Err(Error::bad_input("bus IDs must be positive")
+                        .column(aoc_common::column(&lines[1], x))),
+                    id => Ok(Some(id)),
+                },
+            })
+            .collect::<Result<Vec<Option<usize>>>>()
+            .map_err(|e| e.line(2))?;
+
+        Ok(Notes { earliest, busses })
+    }
+
+    fn part1(&self, notes: &Self::Input) -> Result<Self::Part1> {
+        let mut wait_times: Vec<(usize, usize)> = notes
+            .busses
+            .iter()
+            .filter(|v| v.is_some())
+            .copied()
+            .map(|b| {
+                let b = b.unwrap();
+                (b, b - (notes.earliest % b))
+            })
+            .collect();
+        wait_times.sort_by_key(|(_, w)| *w);
+        let (bus_id, wait_time) = *wait_times
+            .first()
+            .ok_or_else(|| Error::no_solution("no busses are in service"))?;
+
+        let product = bus_id
+            .checked_mul(wait_time)
+            .ok_or_else(|| Error::too_large("the bus ID times the wait"))?;
+
+        Ok(Departure {
+            bus_id,
+            wait_time,
+            product,
+        })
+    }
+
+    fn part2(&self, notes: &Self::Input) -> Result<Self::Part2> {
+        // Bus b leaving i minutes after t means t ≡ -i (mod b).
+        let congruences: Vec<(u64, u64)> = notes
+            .busses
+            .iter()
+            .enumerate()
+            .filter_map(|(i, b)| b.map(|b| (((b - i % b) % b) as u64, b as u64)))
+            .collect();
+
+        if congruences.is_empty() {
+            return Err(Error::no_solution("no busses are in service"));
+        }
+
+        let (t, _) = number_theory::crt(&congruences)
+            .ok_or_else(|| Error::no_solution("no timestamp fits every bus"))?;
+        Ok(t as usize)
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let notes = Day13.parse(lines)?;
+
+    if part.one() {
+        let departure = Day13.part1(&notes)?;
+        println!(
+            "{} * {} = {}",
+            departure.bus_id, departure.wait_time, departure
+        );
+    }
+
+    if part == Part::Both {
+        println!();
+    }
+
+    if part.two() {
+        println!("t = {}", Day13.part2(&notes)?);
+    }
+
+    Ok(())
+}
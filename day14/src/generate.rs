@@ -0,0 +1,49 @@
+use aoc_common::{Generated, Generator, Rng};
+
+use crate::Day14;
+
+/// Writes masks followed by a few memory writes each. The sums depend on
+/// which writes overlap, so no answers are planted.
+///
+/// As in the real inputs, masks have at most nine floating bits, so each
+/// write in part 2 touches at most 512 addresses.
+impl Generator for Day14 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let mut lines = Vec::new();
+
+        while lines.len() < size.max(2) {
+            lines.push(format!("mask = {}", mask(rng)));
+            for _ in 0..rng.range(1..7) {
+                lines.push(format!(
+                    "mem[{}] = {}",
+                    rng.range(0..65536),
+                    rng.range(0..1 << 36)
+                ));
+            }
+        }
+
+        Generated {
+            lines,
+            ..Generated::default()
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        580
+    }
+}
+
+fn mask(rng: &mut Rng) -> String {
+    let mut bits: Vec<char> = (0..36)
+        .map(|_| if rng.chance(1, 2) { '1' } else { '0' })
+        .collect();
+
+    let floating = rng.range(0..10) as usize;
+    let mut positions: Vec<usize> = (0..bits.len()).collect();
+    rng.shuffle(&mut positions);
+    for i in positions.into_iter().take(floating) {
+        bits[i] = 'X';
+    }
+
+    bits.into_iter().collect()
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use aoc_common::{Error, Part, Result, Solution};
+
+mod generate;
+
+/// The error for a sum of the values left in memory too large to hold.
+fn too_large() -> Error {
+    Error::too_large("the sum of the values")
+}
+
+#[derive(Clone, Debug)]
+struct Masker {
+    mask: u64,
+    fill: u64,
+}
+
+impl Masker {
+    fn apply(&self, value: u64) -> u64 {
+        (value & self.mask) | self.fill
+    }
+}
+
+#[derive(Debug)]
+struct MachineV1 {
+    memory: HashMap<u64, u64>,
+    mask: Masker,
+}
+
+impl MachineV1 {
+    fn new() -> Self {
+        Self {
+            memory: HashMap::new(),
+            mask: Masker { mask: 0, fill: 0 },
+        }
+    }
+
+    fn set_mask(&mut self, masker: Masker) {
+        self.mask = masker;
+    }
+
+    fn set_memory(&mut self, address: u64, value: u64) {
+        self.memory.insert(address, self.mask.apply(value));
+    }
+
+    fn sum_values(&self) -> Option<u64> {
+        self.memory
+            .values()
+            .try_fold(0u64, |sum, value| sum.checked_add(*value))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MaskerV2 {
+    mask: u64,
+    floaters: Vec<usize>,
+}
+
+struct AddressGenerator {
+    floaters: Vec<usize>,
+    base: u64,
+    counter: u64,
+    done: bool,
+}
+
+impl AddressGenerator {
+    fn new(mask: &MaskerV2, address: u64) -> Self {
+        let base = address | mask.mask;
+        Self {
+            floaters: mask.floaters.clone(),
+            base,
+            counter: 0,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for AddressGenerator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut bits = Vec::new();
+        let mut address = self.base;
+
+        self.done = true;
+        for idx in 0..self.floaters.len() {
+            let bit = (self.counter >> idx) & 0x1;
+            if bit == 0x0 {
+                self.done = false;
+            }
+            bits.push(bit);
+        }
+
+        for (bit, pos) in bits.iter().zip(self.floaters.iter()) {
+            address = (address & !(1 << pos)) | (bit << pos);
+        }
+
+        self.counter += 1;
+
+        Some(address)
+    }
+}
+
+impl MaskerV2 {
+    fn apply(&self, address: u64) -> AddressGenerator {
+        AddressGenerator::new(self, address)
+    }
+}
+
+#[derive(Debug)]
+struct MachineV2 {
+    memory: HashMap<u64, u64>,
+    mask: MaskerV2,
+}
+
+impl MachineV2 {
+    fn new() -> Self {
+        Self {
+            memory: HashMap::new(),
+            mask: MaskerV2 {
+                mask: 0,
+                floaters: Vec::new(),
+            },
+        }
+    }
+
+    fn set_mask(&mut self, masker: MaskerV2) {
+        self.mask = masker;
+    }
+
+    fn set_memory(&mut self, address: u64, value: u64) {
+        for address in self.mask.apply(address) {
+            self.memory.insert(address, value);
+        }
+    }
+
+    fn sum_values(&self) -> Option<u64> {
+        self.memory
+            .values()
+            .try_fold(0u64, |sum, value| sum.checked_add(*value))
+    }
+}
+
+/// A mask, read once into what each version of the decoder chip needs.
+#[derive(Clone, Debug)]
+pub struct Mask {
+    v1: Masker,
+    v2: MaskerV2,
+}
+
+impl Mask {
+    /// Reads the mask `field`, a slice of `line`, so that errors point at
+    /// the offending bit.
+    fn parse(line: &str, field: &str) -> Result<Self> {
+        let column = aoc_common::column(line, field);
+        if field.len() != 36 {
+            return Err(Error::bad_input("masks must be 36 bits long").column(column));
+        }
+
+        let mut v1 = Masker { mask: 0, fill: 0 };
+        let mut v2 = MaskerV2 {
+            mask: 0,
+            floaters: Vec::new(),
+        };
+
+        for (i, c) in field.chars().enumerate() {
+            let bit = 35 - i;
+            match c {
+                'X' => {
+                    v1.mask |= 1 << bit;
+                    v2.floaters.push(bit);
+                }
+                '1' => {
+                    v1.fill |= 1 << bit;
+                    v2.mask |= 1 << bit;
+                }
+                '0' => (),
+                _ => {
+                    return Err(
+                        Error::bad_input("masks may only contain X, 0 and 1").column(column + i)
+                    )
+                }
+            };
+        }
+
+        Ok(Self { v1, v2 })
+    }
+}
+
+pub enum Command {
+    Mask(Mask),
+    Memory(u64, u64),
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Command>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| {
+            if let Some(mask) = line.strip_prefix("mask = ") {
+                Ok(Command::Mask(Mask::parse(line, mask)?))
+            } else if let Some(rest) = line.strip_prefix("mem[") {
+                let (address, value) = rest.split_once("] = ").ok_or_else(|| {
+                    Error::bad_input("expected mem[<address>] = <value>")
+                        .column(aoc_common::column(line, rest))
+                })?;
+                let address = aoc_common::parse_field(line, address)?;
+                let value = aoc_common::parse_field(line, value)?;
+
+                Ok(Command::Memory(address, value))
+            } else {
+                Err(Error::bad_input("expected a mask or a memory write").column(1))
+            }
+        })
+    }
+
+    fn part1(&self, commands: &Self::Input) -> Result<Self::Part1> {
+        let mut machine = MachineV1::new();
+
+        for command in commands {
+            match command {
+                Command::Mask(mask) => machine.set_mask(mask.v1.clone()),
+                Command::Memory(address, value) => machine.set_memory(*address, *value),
+            }
+        }
+
+        machine.sum_values().ok_or_else(too_large)
+    }
+
+    fn part2(&self, commands: &Self::Input) -> Result<Self::Part2> {
+        let mut machine = MachineV2::new();
+
+        for command in commands {
+            match command {
+                Command::Mask(mask) => machine.set_mask(mask.v2.clone()),
+                Command::Memory(address, value) => machine.set_memory(*address, *value),
+            }
+        }
+
+        machine.sum_values().ok_or_else(too_large)
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let commands = Day14.parse(lines)?;
+
+    if part.one() {
+        println!("Part 1: {}", Day14.part1(&commands)?);
+    }
+
+    if part.two() {
+        println!("Part 2: {}", Day14.part2(&commands)?);
+    }
+
+    Ok(())
+}
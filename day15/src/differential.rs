@@ -0,0 +1,56 @@
+use aoc_common::differential::{removals, Differential};
+use aoc_common::Rng;
+
+use crate::{play_by_search, play_with_memory};
+
+/// The largest size played, as searching back takes time that grows with
+/// the square of the number of turns.
+const MAX_SIZE: u64 = 1000;
+
+/// Plays the same game by searching back and by remembering turns.
+pub struct SearchVsMemory;
+
+impl Differential for SearchVsMemory {
+    const NAME: &'static str = "day 15: search vs memory";
+    const LEFT: &'static str = "search";
+    const RIGHT: &'static str = "memory";
+
+    /// The starting numbers and how many turns to play.
+    type Input = (Vec<usize>, usize);
+    type Output = usize;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Input {
+        let size = (size as u64).min(MAX_SIZE);
+        let numbers = (0..rng.range(1..size + 2))
+            .map(|_| rng.range(0..size + 1) as usize)
+            .collect();
+        (numbers, rng.range(1..size * 20 + 2) as usize)
+    }
+
+    fn shrink(&self, (numbers, turns): &Self::Input) -> Vec<Self::Input> {
+        let mut smaller = Vec::new();
+
+        if *turns > 1 {
+            smaller.push((numbers.clone(), turns / 2));
+            smaller.push((numbers.clone(), turns - 1));
+        }
+        if numbers.len() > 1 {
+            smaller.extend(removals(numbers).into_iter().map(|n| (n, *turns)));
+        }
+        for (i, n) in numbers.iter().enumerate().filter(|(_, n)| **n > 0) {
+            let mut lower = numbers.clone();
+            lower[i] = n / 2;
+            smaller.push((lower, *turns));
+        }
+
+        smaller
+    }
+
+    fn left(&self, (numbers, turns): &Self::Input) -> Self::Output {
+        play_by_search(numbers, *turns)
+    }
+
+    fn right(&self, (numbers, turns): &Self::Input) -> Self::Output {
+        play_with_memory(numbers, *turns)
+    }
+}
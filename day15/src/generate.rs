@@ -0,0 +1,23 @@
+use aoc_common::{Generated, Generator, Rng};
+
+use crate::Day15;
+
+/// Picks distinct starting numbers. The game's answers can only be found by
+/// playing it, so none are planted.
+impl Generator for Day15 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let size = size.max(1);
+        let mut numbers: Vec<usize> = (0..size * 3).collect();
+        rng.shuffle(&mut numbers);
+
+        let numbers: Vec<String> = numbers[..size].iter().map(|n| n.to_string()).collect();
+        Generated {
+            lines: vec![numbers.join(",")],
+            ..Generated::default()
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        7
+    }
+}
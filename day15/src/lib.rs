@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use aoc_common::{trace, Error, Part, Result, Solution};
+
+pub mod differential;
+mod generate;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        if lines.len() != 1 {
+            return Err(Error::bad_input("expected a single line of numbers"));
+        }
+
+        let line = &lines[0];
+        line.split(',')
+            .map(|v| aoc_common::parse_field(line, v))
+            .collect::<Result<_>>()
+            .map_err(|e| e.line(1))
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Self::Part1> {
+        Ok(play_by_search(numbers, 2020))
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Self::Part2> {
+        Ok(play_with_memory(numbers, 30000000))
+    }
+}
+
+/// Plays the game for `turns` turns by searching back through every number
+/// spoken so far.
+fn play_by_search(numbers: &[usize], turns: usize) -> usize {
+    let mut n1 = numbers.to_vec();
+    for _ in n1.len()..turns {
+        let number = *n1.last().unwrap();
+        match n1.iter().rposition(|v| *v == number) {
+            None => n1.push(0),
+            Some(n) => {
+                let value = match n1[..n].iter().rposition(|v| *v == number) {
+                    None => 0,
+                    Some(m) => n - m,
+                };
+                n1.push(value);
+            }
+        }
+    }
+
+    n1[turns - 1]
+}
+
+/// Plays the game for `turns` turns, remembering the last turn each number
+/// was spoken on.
+fn play_with_memory(numbers: &[usize], turns: usize) -> usize {
+    if numbers.len() >= turns {
+        return numbers[turns - 1];
+    }
+
+    let mut numbers = numbers.to_vec();
+    let mut cache = HashMap::new();
+
+    let mut number = numbers.pop().unwrap();
+    for (c, n) in numbers.iter().enumerate() {
+        cache.insert(*n, c);
+    }
+
+    for c in numbers.len()..turns - 1 {
+        if c % 1000000 == 0 {
+            trace!("... {:8}", c);
+        }
+        let old_number = number;
+
+        number = match cache.get(&number) {
+            None => 0,
+            Some(v) => c - *v,
+        };
+        cache.insert(old_number, c);
+    }
+
+    number
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let numbers = Day15.parse(lines)?;
+
+    if part.one() {
+        println!("{}", Day15.part1(&numbers)?);
+    }
+
+    if part.two() {
+        println!("{}", Day15.part2(&numbers)?);
+    }
+
+    Ok(())
+}
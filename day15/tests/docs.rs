@@ -0,0 +1,27 @@
+use std::process::Command;
+
+fn day15(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_day15"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "day15 {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn documentation_covers_the_starting_numbers() {
+    assert!(day15(&["completions", "bash"]).contains("<start>"));
+    assert!(day15(&["completions", "zsh"]).contains(":start -- Starting numbers:"));
+    assert!(day15(&["completions", "fish"]).contains("complete -c day15"));
+
+    let page = day15(&["man"]);
+    assert!(page.contains("day15 [FLAGS] [OPTIONS] <start>"));
+    assert!(page.contains(".SS \"day15 completions\""));
+    assert!(page.contains(".SS \"day15 man\""));
+}
+
+#[test]
+fn starting_numbers_still_solve() {
+    assert_eq!(day15(&["0,3,6", "--part", "1"]), "436\n");
+}
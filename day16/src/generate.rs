@@ -0,0 +1,136 @@
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::Day16;
+
+const FIELDS: &[&str] = &[
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// The lowest valid value. Anything below it is invalid for every field.
+const BASE: usize = 25;
+
+/// Plants a field order that can only be worked out one way.
+///
+/// The valid values are split into consecutive bands, and the field ranked
+/// `r` accepts the first `r` of them. Each position also has a rank, and
+/// only ever holds values from the bands up to its rank, including at least
+/// one from the band of that rank. So the position ranked `r` fits exactly
+/// the fields ranked `r` and above, and the highest ranked position has
+/// only one field to choose from, then the next, and so on.
+///
+/// Each field's second range is a decoy above all the bands, and invalid
+/// values are drawn from the gaps below and between the decoys.
+impl Generator for Day16 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let fields = FIELDS.len();
+
+        // bands[r] is the band of values for rank r.
+        let mut bands = Vec::new();
+        let mut start = BASE;
+        for _ in 0..fields {
+            let width = rng.range(5..20) as usize;
+            bands.push(start..start + width);
+            start += width;
+        }
+        let decoys = start + 50;
+
+        // The field and the position holding each rank.
+        let mut names: Vec<&str> = FIELDS.to_vec();
+        rng.shuffle(&mut names);
+        let mut positions: Vec<usize> = (0..fields).collect();
+        rng.shuffle(&mut positions);
+
+        let mut lines: Vec<String> = (0..fields)
+            .map(|rank| {
+                let decoy = decoys + rank * 20;
+                format!(
+                    "{}: {}-{} or {}-{}",
+                    names[rank],
+                    BASE,
+                    bands[rank].end - 1,
+                    decoy,
+                    decoy + 9
+                )
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+
+        let ticket = |rng: &mut Rng, exact: bool| {
+            let mut values = vec![0; fields];
+            for (rank, position) in positions.iter().enumerate() {
+                let band = if exact { rank } else { rng.index(rank + 1) };
+                values[*position] = rng.range(bands[band].start as u64..bands[band].end as u64);
+            }
+            values
+        };
+
+        let yours = ticket(rng, false);
+        let departure_product: u64 = positions
+            .iter()
+            .enumerate()
+            .filter(|(rank, _)| names[*rank].starts_with("departure"))
+            .map(|(_, position)| yours[*position])
+            .product();
+
+        let mut error_rate = 0;
+        let mut nearby = vec![ticket(rng, true)];
+        for _ in 1..size.max(1) {
+            let mut values = ticket(rng, false);
+            if rng.chance(1, 4) {
+                let invalid = if rng.chance(1, 2) {
+                    rng.range(1..BASE as u64)
+                } else {
+                    let gap = decoys + rng.index(fields) * 20 + 10;
+                    rng.range(gap as u64..gap as u64 + 10)
+                };
+                values[rng.index(fields)] = invalid;
+                error_rate += invalid;
+            }
+            nearby.push(values);
+        }
+        rng.shuffle(&mut nearby);
+
+        let line = |values: &[u64]| -> String {
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            values.join(",")
+        };
+
+        lines.push(String::new());
+        lines.push(String::from("your ticket:"));
+        lines.push(line(&yours));
+        lines.push(String::new());
+        lines.push(String::from("nearby tickets:"));
+        lines.extend(nearby.iter().map(|values| line(values)));
+
+        Generated {
+            lines,
+            answers: Answers {
+                part1: Some(error_rate.to_string()),
+                part2: Some(departure_product.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        240
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use aoc_common::serde_json::{json, Value};
+use aoc_common::{trace, Answer, Error, Part, Result, Solution};
+
+mod generate;
+
+#[derive(Debug, PartialEq)]
+enum ParserState {
+    Fields,
+    Label,
+    YourTicket,
+    NearbyTickets,
+}
+
+fn valid_fields_for_position(
+    fields: &HashMap<String, Vec<RangeInclusive<usize>>>,
+    values: Vec<usize>,
+) -> HashSet<String> {
+    let mut valid_fields = HashSet::new();
+
+    for (field_name, ranges) in fields {
+        let mut valid = true;
+        for value in &values {
+            let mut value_valid = false;
+            for range in ranges {
+                if range.contains(value) {
+                    value_valid = true;
+                    break;
+                }
+            }
+
+            if !value_valid {
+                valid = false;
+                break;
+            }
+        }
+
+        if valid {
+            valid_fields.insert(field_name.clone());
+        }
+    }
+
+    valid_fields
+}
+
+fn parse_rule(line: &str) -> Result<(String, Vec<RangeInclusive<usize>>)> {
+    let (field_name, ranges) = line
+        .split_once(": ")
+        .ok_or_else(|| Error::bad_input("expected \"<field>: <range> or <range>\""))?;
+    let ranges = ranges
+        .split(" or ")
+        .map(|range| {
+            let (low, high) = range.split_once('-').ok_or_else(|| {
+                Error::bad_input("expected a range such as 1-3")
+                    .column(aoc_common::column(line, range))
+            })?;
+            let low: usize = aoc_common::parse_field(line, low)?;
+            let high: usize = aoc_common::parse_field(line, high)?;
+            Ok(low..=high)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((field_name.to_string(), ranges))
+}
+
+fn parse_ticket(line: &str, fields: usize) -> Result<Vec<usize>> {
+    let values = line
+        .split(',')
+        .map(|v| aoc_common::parse_field(line, v))
+        .collect::<Result<Vec<usize>>>()?;
+
+    if values.len() != fields {
+        return Err(Error::bad_input(format!(
+            "expected {} values, got {}",
+            fields,
+            values.len()
+        )));
+    }
+
+    Ok(values)
+}
+
+pub struct Notes {
+    fields: HashMap<String, Vec<RangeInclusive<usize>>>,
+    ticket: Vec<usize>,
+    nearby_tickets: Vec<Vec<usize>>,
+}
+
+fn scan_tickets(notes: &Notes) -> Result<(usize, Vec<Vec<usize>>)> {
+    let mut error_rate = 0;
+    let mut valid_tickets = Vec::new();
+
+    for nt in notes.nearby_tickets.iter() {
+        let mut valid_ticket = true;
+
+        for value in nt.iter() {
+            let mut valid = false;
+
+            for ranges in notes.fields.values() {
+                for range in ranges {
+                    if range.contains(value) {
+                        valid = true;
+                        break;
+                    }
+                }
+
+                if valid {
+                    break;
+                }
+            }
+
+            if !valid {
+                error_rate = value
+                    .checked_add(error_rate)
+                    .ok_or_else(|| Error::too_large("the error rate"))?;
+                valid_ticket = false;
+            }
+        }
+
+        if valid_ticket {
+            valid_tickets.push(nt.clone());
+        }
+    }
+
+    Ok((error_rate, valid_tickets))
+}
+
+pub struct Ticket {
+    pub field_order: Vec<String>,
+    pub values: HashMap<String, usize>,
+    departure_product: usize,
+}
+
+impl Ticket {
+    /// The ticket with fields in `field_order`, or `None` if the product of
+    /// its departure fields is too large.
+    pub fn new(field_order: Vec<String>, values: HashMap<String, usize>) -> Option<Self> {
+        let departure_product = values
+            .iter()
+            .filter(|(k, _)| k.starts_with("departure"))
+            .try_fold(1usize, |product, (_, v)| product.checked_mul(*v))?;
+
+        Some(Self {
+            field_order,
+            values,
+            departure_product,
+        })
+    }
+
+    /// The product of the departure fields.
+    pub fn departure_product(&self) -> usize {
+        self.departure_product
+    }
+}
+
+impl fmt::Display for Ticket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.departure_product)
+    }
+}
+
+impl Answer for Ticket {
+    fn diagnostics(&self) -> Option<Value> {
+        Some(json!({ "field_order": self.field_order }))
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+    type Part1 = usize;
+    type Part2 = Ticket;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let mut state = ParserState::Fields;
+        let mut fields = HashMap::new();
+        let mut ticket: Option<Vec<usize>> = None;
+        let mut nearby_tickets: Vec<Vec<usize>> = Vec::new();
+
+        for (n, line) in lines.iter().enumerate() {
+            match line.as_str() {
+                "" => {
+                    state = ParserState::Label;
+                    continue;
+                }
+                "your ticket:" => {
+                    state = ParserState::YourTicket;
+                    continue;
+                }
+                "nearby tickets:" => {
+                    state = ParserState::NearbyTickets;
+                    continue;
+                }
+                _ => (),
+            };
+
+            let parsed: Result<()> = match state {
+                ParserState::Fields => parse_rule(line).map(|(field_name, ranges)| {
+                    fields.insert(field_name, ranges);
+                }),
+                ParserState::Label => Err(Error::bad_input(format!(
+                    "unexpected line {:?} after a blank line",
+                    line
+                ))),
+                ParserState::YourTicket => {
+                    parse_ticket(line, fields.len()).map(|values| ticket = Some(values))
+                }
+                ParserState::NearbyTickets => {
+                    parse_ticket(line, fields.len()).map(|values| nearby_tickets.push(values))
+                }
+            };
+            parsed.map_err(|e| e.line(n + 1))?;
+        }
+
+        let ticket = ticket.ok_or_else(|| Error::bad_input("no \"your ticket:\" section"))?;
+
+        Ok(Notes {
+            fields,
+            ticket,
+            nearby_tickets,
+        })
+    }
+
+    fn part1(&self, notes: &Self::Input) -> Result<Self::Part1> {
+        let (error_rate, _) = scan_tickets(notes)?;
+        Ok(error_rate)
+    }
+
+    fn part2(&self, notes: &Self::Input) -> Result<Self::Part2> {
+        let (_, valid_tickets) = scan_tickets(notes)?;
+
+        let mut valid_fields = Vec::new();
+
+        for position in 0..notes.fields.len() {
+            let values: Vec<usize> = valid_tickets.iter().map(|t| t[position]).collect();
+            let valid = valid_fields_for_position(&notes.fields, values);
+            valid_fields.push(valid);
+        }
+
+        let mut fixed_fields = HashSet::new();
+
+        loop {
+            let singleton = valid_fields
+                .iter()
+                .find(|v| v.len() == 1 && v.intersection(&fixed_fields).count() == 0)
+                .ok_or_else(|| Error::no_solution("the field order is ambiguous"))?
+                .clone();
+            valid_fields = valid_fields
+                .iter()
+                .map(|v| {
+                    if v.len() == 1 {
+                        v.clone()
+                    } else {
+                        let new_v: HashSet<String> = v.difference(&singleton).cloned().collect();
+                        new_v
+                    }
+                })
+                .collect();
+            fixed_fields.insert(singleton.iter().next().unwrap().clone());
+
+            let mut all_fixed = true;
+            for field in valid_fields.iter() {
+                if field.len() > 1 {
+                    all_fixed = false;
+                    break;
+                }
+            }
+            if all_fixed {
+                break;
+            }
+        }
+
+        let field_order: Vec<String> = valid_fields
+            .iter()
+            .map(|v| {
+                v.iter()
+                    .next()
+                    .cloned()
+                    .ok_or_else(|| Error::no_solution("a position fits no field"))
+            })
+            .collect::<Result<_>>()?;
+
+        let values: HashMap<String, usize> = field_order
+            .iter()
+            .cloned()
+            .zip(notes.ticket.iter().copied())
+            .collect();
+
+        Ticket::new(field_order, values).ok_or_else(|| Error::too_large("the departure product"))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let notes = Day16.parse(lines)?;
+
+    if part.one() {
+        println!("error_rate: {}", Day16.part1(&notes)?);
+    }
+
+    if part == Part::Both {
+        println!();
+    }
+
+    if part.two() {
+        let ticket = Day16.part2(&notes)?;
+        trace!("{:?}", ticket.field_order);
+        trace!("{:?}", ticket.values);
+        println!("departure sum: {}", ticket);
+    }
+
+    Ok(())
+}
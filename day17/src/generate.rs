@@ -0,0 +1,26 @@
+use aoc_common::{Generated, Generator, Rng};
+
+use crate::Day17;
+
+/// Scatters active cubes over a square slice. The cycles spread in every
+/// direction from there, so no answers are planted.
+impl Generator for Day17 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let size = size.max(1);
+
+        Generated {
+            lines: (0..size)
+                .map(|_| {
+                    (0..size)
+                        .map(|_| if rng.chance(1, 2) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect(),
+            ..Generated::default()
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        8
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc_common::{trace, Answer, Frame, Grid, Part, Result, Solution, Verbosity};
+
+mod generate;
+
+pub struct EnergySource3 {
+    volume: HashMap<(isize, isize, isize), bool>,
+    x_min: isize,
+    x_max: isize,
+    y_min: isize,
+    y_max: isize,
+    z_min: isize,
+    z_max: isize,
+}
+
+/// Shows each z slice of the active region, as in the puzzle text.
+impl fmt::Display for EnergySource3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for z in self.z_min..self.z_max + 1 {
+            if z != self.z_min {
+                writeln!(f)?;
+            }
+            writeln!(f, "z={}", z)?;
+            for y in self.y_min..self.y_max + 1 {
+                let row: String = (self.x_min..self.x_max + 1)
+                    .map(|x| match self.is_cube_active(x, y, z) {
+                        true => '#',
+                        false => '.',
+                    })
+                    .collect();
+                writeln!(f, "{}", row)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl EnergySource3 {
+    fn add(&mut self, x: isize, y: isize, z: isize, active: bool) {
+        self.volume.insert((x, y, z), active);
+        if active {
+            self.x_min = self.x_min.min(x);
+            self.x_max = self.x_max.max(x);
+            self.y_min = self.y_min.min(y);
+            self.y_max = self.y_max.max(y);
+            self.z_min = self.z_min.min(z);
+            self.z_max = self.z_max.max(z);
+        }
+    }
+
+    fn print(&self) {
+        trace!("{}", self);
+    }
+
+    fn is_cube_active(&self, x: isize, y: isize, z: isize) -> bool {
+        match self.volume.get(&(x, y, z)) {
+            None => false,
+            Some(v) => *v,
+        }
+    }
+
+    fn active_neighbours(&self, x: isize, y: isize, z: isize) -> usize {
+        let mut count = 0;
+
+        for cz in z - 1..z + 2 {
+            for cy in y - 1..y + 2 {
+                for cx in x - 1..x + 2 {
+                    if (cx, cy, cz) == (x, y, z) {
+                        continue;
+                    }
+
+                    if self.is_cube_active(cx, cy, cz) {
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    fn cycle(&self) -> Self {
+        let mut source = Self {
+            volume: HashMap::new(),
+            x_min: 0,
+            x_max: 0,
+            y_min: 0,
+            y_max: 0,
+            z_min: 0,
+            z_max: 0,
+        };
+
+        for z in self.z_min - 1..self.z_max + 2 {
+            for y in self.y_min - 1..self.y_max + 2 {
+                for x in self.x_min - 1..self.x_max + 2 {
+                    let active = self.is_cube_active(x, y, z);
+                    let active_neighbours = self.active_neighbours(x, y, z);
+                    let active = match (active, active_neighbours) {
+                        (true, 2) => true,
+                        (true, 3) => true,
+                        (true, _) => false,
+                        (false, 3) => true,
+                        (false, _) => false,
+                    };
+                    source.add(x, y, z, active);
+                }
+            }
+        }
+
+        source
+    }
+
+    fn active_count(&self) -> usize {
+        self.volume.values().filter(|v| **v).count()
+    }
+}
+
+impl From<&Grid<bool>> for EnergySource3 {
+    fn from(slice: &Grid<bool>) -> Self {
+        let mut source = Self {
+            volume: HashMap::new(),
+            x_min: 0,
+            x_max: 0,
+            y_min: 0,
+            y_max: 0,
+            z_min: 0,
+            z_max: 0,
+        };
+
+        for ((x, y), active) in slice.positions() {
+            source.add(x as isize, y as isize, 0, *active);
+        }
+
+        source
+    }
+}
+
+pub struct EnergySource4 {
+    volume: HashMap<(isize, isize, isize, isize), bool>,
+    x_min: isize,
+    x_max: isize,
+    y_min: isize,
+    y_max: isize,
+    z_min: isize,
+    z_max: isize,
+    w_min: isize,
+    w_max: isize,
+}
+
+impl EnergySource4 {
+    fn add(&mut self, x: isize, y: isize, z: isize, w: isize, active: bool) {
+        self.volume.insert((x, y, z, w), active);
+        if active {
+            self.x_min = self.x_min.min(x);
+            self.x_max = self.x_max.max(x);
+            self.y_min = self.y_min.min(y);
+            self.y_max = self.y_max.max(y);
+            self.z_min = self.z_min.min(z);
+            self.z_max = self.z_max.max(z);
+            self.w_min = self.w_min.min(w);
+            self.w_max = self.w_max.max(w);
+        }
+    }
+
+    fn print(&self) {
+        if !aoc_common::enabled(Verbosity::Trace) {
+            return;
+        }
+
+        for w in self.w_min..self.w_max + 1 {
+            for z in self.z_min..self.z_max + 1 {
+                trace!("z={}, w={}", z, w);
+                for y in self.y_min..self.y_max + 1 {
+                    let row: String = (self.x_min..self.x_max + 1)
+                        .map(|x| match self.volume.get(&(x, y, z, w)).unwrap() {
+                            true => '#',
+                            false => '.',
+                        })
+                        .collect();
+                    trace!("{}", row);
+                }
+                trace!();
+            }
+        }
+    }
+
+    fn is_cube_active(&self, x: isize, y: isize, z: isize, w: isize) -> bool {
+        match self.volume.get(&(x, y, z, w)) {
+            None => false,
+            Some(val) => *val,
+        }
+    }
+
+    fn active_neighbours(&self, x: isize, y: isize, z: isize, w: isize) -> usize {
+        let mut count = 0;
+
+        for cw in w - 1..w + 2 {
+            for cz in z - 1..z + 2 {
+                for cy in y - 1..y + 2 {
+                    for cx in x - 1..x + 2 {
+                        if (cx, cy, cz, cw) == (x, y, z, w) {
+                            continue;
+                        }
+
+                        if self.is_cube_active(cx, cy, cz, cw) {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    fn cycle(&self) -> Self {
+        let mut source = Self {
+            volume: HashMap::new(),
+            x_min: 0,
+            x_max: 0,
+            y_min: 0,
+            y_max: 0,
+            z_min: 0,
+            z_max: 0,
+            w_min: 0,
+            w_max: 0,
+        };
+
+        for w in self.w_min - 1..self.w_max + 2 {
+            for z in self.z_min - 1..self.z_max + 2 {
+                for y in self.y_min - 1..self.y_max + 2 {
+                    for x in self.x_min - 1..self.x_max + 2 {
+                        let active = self.is_cube_active(x, y, z, w);
+                        let active_neighbours = self.active_neighbours(x, y, z, w);
+                        let active = match (active, active_neighbours) {
+                            (true, 2) => true,
+                            (true, 3) => true,
+                            (true, _) => false,
+                            (false, 3) => true,
+                            (false, _) => false,
+                        };
+                        source.add(x, y, z, w, active);
+                    }
+                }
+            }
+        }
+
+        source
+    }
+
+    fn active_count(&self) -> usize {
+        self.volume.values().filter(|v| **v).count()
+    }
+}
+
+impl From<&Grid<bool>> for EnergySource4 {
+    fn from(slice: &Grid<bool>) -> Self {
+        let mut source = Self {
+            volume: HashMap::new(),
+            x_min: 0,
+            x_max: 0,
+            y_min: 0,
+            y_max: 0,
+            z_min: 0,
+            z_max: 0,
+            w_min: 0,
+            w_max: 0,
+        };
+
+        for ((x, y), active) in slice.positions() {
+            source.add(x as isize, y as isize, 0, 0, *active);
+        }
+
+        source
+    }
+}
+
+/// The pocket dimension after booting, which answers with its active
+/// cube count.
+pub struct Booted(pub EnergySource3);
+
+impl fmt::Display for Booted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.active_count())
+    }
+}
+
+impl Answer for Booted {
+    fn visualisation(&self) -> Option<String> {
+        Some(self.0.to_string())
+    }
+}
+
+/// Runs the six boot cycles in three dimensions, showing `observe` the
+/// starting state as cycle 0 and then the state after each cycle.
+fn boot(slice: &Grid<bool>, mut observe: impl FnMut(usize, &EnergySource3)) -> EnergySource3 {
+    let mut source = EnergySource3::from(slice);
+    observe(0, &source);
+
+    for cycle in 1..7 {
+        source = source.cycle();
+        observe(cycle, &source);
+    }
+
+    source
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<bool>;
+    type Part1 = Booted;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        Grid::parse(lines, |ch| match ch {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        })
+    }
+
+    fn part1(&self, slice: &Self::Input) -> Result<Self::Part1> {
+        Ok(Booted(boot(slice, |cycle, source| {
+            if cycle > 0 {
+                trace!("Cycle {}:", cycle);
+            }
+            source.print();
+        })))
+    }
+
+    fn part2(&self, slice: &Self::Input) -> Result<Self::Part2> {
+        let mut source = EnergySource4::from(slice);
+        source.print();
+
+        for cycle in 1..7 {
+            trace!("Cycle {}:", cycle);
+            source = source.cycle();
+            source.print();
+        }
+
+        Ok(source.active_count())
+    }
+}
+
+/// The slices of part 1's pocket dimension as it starts and after each
+/// cycle. Part 2's four dimensions are not drawn.
+pub fn frames(lines: &[String]) -> Result<Vec<Frame>> {
+    let slice = Day17.parse(lines)?;
+
+    let mut frames = Vec::new();
+    boot(&slice, |cycle, source| {
+        let title = match cycle {
+            0 => String::from("Start"),
+            cycle => format!("Cycle {}", cycle),
+        };
+        frames.push(Frame::new(title, source));
+    });
+
+    Ok(frames)
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let slice = Day17.parse(lines)?;
+
+    if part.one() {
+        println!("active count: {}", Day17.part1(&slice)?);
+    }
+
+    if part.two() {
+        println!("active count: {}", Day17.part2(&slice)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use aoc_common::differential::Differential;
+use aoc_common::Rng;
+
+use crate::generate::{group, Term};
+use crate::{evaluate, evaluate2, tokenise};
+
+/// The largest value a generated expression may have, so that neither side
+/// overflows.
+const MAX_VALUE: u64 = 1_000_000_000_000;
+
+/// Makes an expression no deeper than `size` allows whose value stays small
+/// under both sets of rules.
+fn expression(rng: &mut Rng, size: usize) -> Term {
+    loop {
+        let term = group(rng, size.min(6) / 2);
+        let values = term.in_order().zip(term.additions_first());
+        if values.is_some_and(|(a, b)| a.max(b) <= MAX_VALUE) {
+            return term;
+        }
+    }
+}
+
+/// Terms a little simpler than `term`: a group replaced by one of its
+/// terms or with a term left out, a number replaced by 1, or any of these
+/// applied to one of its terms.
+fn simpler(term: &Term) -> Vec<Term> {
+    match term {
+        Term::Number(1) => Vec::new(),
+        Term::Number(_) => vec![Term::Number(1)],
+        Term::Group(terms, operators) => {
+            let mut simpler: Vec<Term> = terms.to_vec();
+
+            if terms.len() > 2 {
+                for i in 0..terms.len() {
+                    let mut fewer = terms.clone();
+                    let mut operators = operators.clone();
+                    fewer.remove(i);
+                    operators.remove(i.saturating_sub(1));
+                    simpler.push(Term::Group(fewer, operators));
+                }
+            }
+
+            for (i, inner) in terms.iter().enumerate() {
+                for replacement in self::simpler(inner) {
+                    let mut terms = terms.clone();
+                    terms[i] = replacement;
+                    simpler.push(Term::Group(terms, operators.clone()));
+                }
+            }
+
+            // A bare number is not a valid line on its own.
+            simpler.retain(|t| matches!(t, Term::Group(..)));
+            simpler
+        }
+    }
+}
+
+fn tokenised(term: &Term) -> Option<Vec<crate::Token>> {
+    tokenise(&term.to_string()).ok()
+}
+
+/// Evaluates strictly from left to right, against a tree-walking reference.
+pub struct InOrder;
+
+impl Differential for InOrder {
+    const NAME: &'static str = "day 18: evaluate vs reference";
+    const LEFT: &'static str = "evaluate";
+    const RIGHT: &'static str = "reference";
+
+    type Input = Term;
+    type Output = Option<u64>;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Input {
+        expression(rng, size)
+    }
+
+    fn shrink(&self, term: &Self::Input) -> Vec<Self::Input> {
+        simpler(term)
+    }
+
+    fn left(&self, term: &Self::Input) -> Self::Output {
+        tokenised(term)
+            .and_then(|tokens| evaluate(&mut tokens.into_iter()))
+            .map(|value| value as u64)
+    }
+
+    fn right(&self, term: &Self::Input) -> Self::Output {
+        term.in_order()
+    }
+}
+
+/// Evaluates addition before multiplication, against a tree-walking
+/// reference.
+pub struct AdditionsFirst;
+
+impl Differential for AdditionsFirst {
+    const NAME: &'static str = "day 18: evaluate2 vs reference";
+    const LEFT: &'static str = "evaluate2";
+    const RIGHT: &'static str = "reference";
+
+    type Input = Term;
+    type Output = Option<u64>;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Input {
+        expression(rng, size)
+    }
+
+    fn shrink(&self, term: &Self::Input) -> Vec<Self::Input> {
+        simpler(term)
+    }
+
+    fn left(&self, term: &Self::Input) -> Self::Output {
+        tokenised(term)
+            .and_then(evaluate2)
+            .map(|value| value as u64)
+    }
+
+    fn right(&self, term: &Self::Input) -> Self::Output {
+        term.additions_first()
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+
+use aoc_common::{Answers, Generated, Generator, Rng};
+
+use crate::Day18;
+
+/// The largest value any line may have under either set of rules, which
+/// keeps the totals of even very long inputs inside a `u64`.
+const MAX_VALUE: u64 = 10_000_000_000_000;
+
+/// An expression as a tree, which can be evaluated without tokenising it.
+#[derive(Clone, Debug)]
+pub enum Term {
+    Number(u64),
+    Group(Vec<Term>, Vec<char>),
+}
+
+impl Term {
+    /// Evaluates the term strictly from left to right.
+    pub(crate) fn in_order(&self) -> Option<u64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Group(terms, operators) => {
+                let mut value = terms[0].in_order()?;
+                for (term, operator) in terms[1..].iter().zip(operators) {
+                    let term = term.in_order()?;
+                    value = match operator {
+                        '+' => value.checked_add(term)?,
+                        _ => value.checked_mul(term)?,
+                    };
+                }
+                Some(value)
+            }
+        }
+    }
+
+    /// Evaluates the term with addition before multiplication.
+    pub(crate) fn additions_first(&self) -> Option<u64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Group(terms, operators) => {
+                let mut product = 1u64;
+                let mut sum = terms[0].additions_first()?;
+                for (term, operator) in terms[1..].iter().zip(operators) {
+                    let term = term.additions_first()?;
+                    if *operator == '+' {
+                        sum = sum.checked_add(term)?;
+                    } else {
+                        product = product.checked_mul(sum)?;
+                        sum = term;
+                    }
+                }
+                product.checked_mul(sum)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Group(terms, operators) => {
+                write!(f, "{}", Bracketed(&terms[0]))?;
+                for (term, operator) in terms[1..].iter().zip(operators) {
+                    write!(f, " {} {}", operator, Bracketed(term))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Displays a term inside a group, in brackets if it is a group itself.
+struct Bracketed<'a>(&'a Term);
+
+impl fmt::Display for Bracketed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Term::Number(_) => write!(f, "{}", self.0),
+            Term::Group(..) => write!(f, "({})", self.0),
+        }
+    }
+}
+
+fn term(rng: &mut Rng, depth: usize) -> Term {
+    if depth == 0 || rng.chance(3, 4) {
+        return Term::Number(rng.range(1..10));
+    }
+
+    group(rng, depth - 1)
+}
+
+pub(crate) fn group(rng: &mut Rng, depth: usize) -> Term {
+    let len = rng.range(2..7) as usize;
+    let terms = (0..len).map(|_| term(rng, depth)).collect();
+    let operators = (1..len).map(|_| *rng.choose(&['+', '*'])).collect();
+    Term::Group(terms, operators)
+}
+
+/// Builds random expressions and works out both values while it still has
+/// them as trees.
+impl Generator for Day18 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> Generated {
+        let mut lines = Vec::new();
+        let mut in_order = 0;
+        let mut additions_first = 0;
+
+        while lines.len() < size.max(1) {
+            let expression = group(rng, 3);
+            let values = expression.in_order().zip(expression.additions_first());
+            if let Some((first, second)) = values.filter(|(a, b)| a.max(b) <= &MAX_VALUE) {
+                lines.push(expression.to_string());
+                in_order += first;
+                additions_first += second;
+            }
+        }
+
+        Generated {
+            lines,
+            answers: Answers {
+                part1: Some(in_order.to_string()),
+                part2: Some(additions_first.to_string()),
+            },
+        }
+    }
+
+    fn default_size(&self) -> usize {
+        380
+    }
+}
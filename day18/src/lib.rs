@@ -0,0 +1,231 @@
+use aoc_common::{trace, Error, Part, Result, Solution};
+
+pub mod differential;
+mod generate;
+
+#[derive(Clone, Copy)]
+enum Operator {
+    None,
+    Add,
+    Multiply,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Token {
+    Add,
+    Multiply,
+    Value(usize),
+    StartParen,
+    EndParen,
+}
+
+/// The deepest nesting of parentheses accepted, far beyond any real input,
+/// which keeps the recursive evaluators from running out of stack.
+const MAX_DEPTH: usize = 256;
+
+fn tokenise(line: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut depth = 0;
+    let mut expect_operand = true;
+
+    for part in line.split(' ') {
+        let bad = |cause: &str| Err(Error::bad_input(cause).column(aoc_common::column(line, part)));
+
+        let inner = part.trim_start_matches('(');
+        let body = inner.trim_end_matches(')');
+
+        for _ in 0..part.len() - inner.len() {
+            if !expect_operand {
+                return bad("expected an operator before (");
+            }
+            if depth == MAX_DEPTH {
+                return bad("the parentheses are nested too deeply");
+            }
+            tokens.push(Token::StartParen);
+            depth += 1;
+        }
+
+        let token = match body {
+            "+" => Token::Add,
+            "*" => Token::Multiply,
+            x => Token::Value(aoc_common::parse_field(line, x)?),
+        };
+        match (token, expect_operand) {
+            (Token::Value(_), true) => expect_operand = false,
+            (Token::Value(_), false) => return bad("expected an operator"),
+            (_, false) => expect_operand = true,
+            (_, true) => return bad("expected a number"),
+        }
+        tokens.push(token);
+
+        for _ in 0..inner.len() - body.len() {
+            if expect_operand || depth == 0 {
+                return bad("unexpected )");
+            }
+            tokens.push(Token::EndParen);
+            depth -= 1;
+        }
+    }
+
+    if expect_operand {
+        Err(Error::bad_input("the expression ends early").column(line.len() + 1))
+    } else if depth > 0 {
+        Err(Error::bad_input("unclosed (").column(line.len() + 1))
+    } else {
+        Ok(tokens)
+    }
+}
+
+/// Applies `operator` to the value so far and `x`, or `None` on overflow.
+fn apply(operator: Operator, accumulator: usize, x: usize) -> Option<usize> {
+    match operator {
+        Operator::None => Some(x),
+        Operator::Add => accumulator.checked_add(x),
+        Operator::Multiply => accumulator.checked_mul(x),
+    }
+}
+
+/// Evaluates strictly from left to right, or returns `None` if the value
+/// overflows.
+fn evaluate<I: Iterator<Item = Token>>(tokens: &mut I) -> Option<usize> {
+    let mut accumulator = 0;
+    let mut operator = Operator::None;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Add => operator = Operator::Add,
+            Token::Multiply => operator = Operator::Multiply,
+            Token::Value(x) => accumulator = apply(operator, accumulator, x)?,
+            Token::StartParen => {
+                let x = evaluate(tokens)?;
+                accumulator = apply(operator, accumulator, x)?;
+            }
+            Token::EndParen => break,
+        }
+    }
+
+    Some(accumulator)
+}
+
+/// Evaluates additions before multiplications, or returns `None` if the
+/// value overflows.
+fn evaluate2(tokens: Vec<Token>) -> Option<usize> {
+    let mut tokens = tokens;
+    let mut new_tokens = Vec::new();
+
+    while let Some(start) = tokens.iter().position(|t| *t == Token::StartParen) {
+        let mut end = start;
+        let mut paren_depth = 0;
+        for (pos, token) in tokens.iter().enumerate().skip(start + 1) {
+            match token {
+                Token::StartParen => paren_depth += 1,
+                Token::EndParen => {
+                    if paren_depth == 0 {
+                        end = pos;
+                        break;
+                    } else {
+                        paren_depth -= 1;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        let paren_span: Vec<Token> = tokens
+            .iter()
+            .skip(start + 1)
+            .take(end - start - 1)
+            .copied()
+            .collect();
+
+        let result = evaluate2(paren_span)?;
+
+        new_tokens.extend(tokens.iter().take(start).copied());
+        new_tokens.push(Token::Value(result));
+        new_tokens.extend(tokens.iter().skip(end + 1).copied());
+        tokens = new_tokens;
+        new_tokens = Vec::new();
+    }
+
+    let mut in_add = false;
+    let mut lhs: usize = 0;
+
+    for token in tokens.iter() {
+        match token {
+            Token::Value(value) => {
+                if in_add {
+                    lhs = lhs.checked_add(*value)?;
+                    new_tokens.pop();
+                    new_tokens.push(Token::Value(lhs));
+                    in_add = false;
+                } else {
+                    lhs = *value;
+                    new_tokens.push(Token::Value(*value));
+                }
+            }
+            Token::Add => in_add = true,
+            t => new_tokens.push(*t),
+        }
+    }
+
+    new_tokens
+        .iter()
+        .filter_map(|t| match t {
+            Token::Value(x) => Some(*x),
+            _ => None,
+        })
+        .try_fold(1usize, |product, x| product.checked_mul(x))
+}
+
+/// Sums the values of `expressions` under `evaluate`, failing if any of
+/// them overflows.
+fn total<F>(expressions: &[(String, Vec<Token>)], evaluate: F) -> Result<usize>
+where
+    F: Fn(&[Token]) -> Option<usize>,
+{
+    let mut sum: usize = 0;
+
+    for (n, (line, tokens)) in expressions.iter().enumerate() {
+        let result = evaluate(tokens).ok_or_else(|| Error::too_large("the value").line(n + 1))?;
+        trace!("{} = {}", line, result);
+        sum = sum
+            .checked_add(result)
+            .ok_or_else(|| Error::too_large("the total").line(n + 1))?;
+    }
+
+    Ok(sum)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(String, Vec<Token>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        aoc_common::parse_lines(lines, |line| Ok((line.to_string(), tokenise(line)?)))
+    }
+
+    fn part1(&self, expressions: &Self::Input) -> Result<Self::Part1> {
+        total(expressions, |tokens| evaluate(&mut tokens.iter().cloned()))
+    }
+
+    fn part2(&self, expressions: &Self::Input) -> Result<Self::Part2> {
+        total(expressions, |tokens| evaluate2(tokens.to_vec()))
+    }
+}
+
+pub fn run(lines: &[String], part: Part) -> Result<()> {
+    let expressions = Day18.parse(lines)?;
+
+    if part.one() {
+        println!("Total 1: {}", Day18.part1(&expressions)?);
+    }
+
+    if part.two() {
+        println!("Total 2: {}", Day18.part2(&expressions)?);
+    }
+
+    Ok(())
+}
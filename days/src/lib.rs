@@ -0,0 +1,145 @@
+//! Every day's solver and the ways of reaching it by number, shared by the
+//! `aoc` runner and the C library so the library stays free of the
+//! runner's own dependencies.
+
+use std::sync::Arc;
+
+use aoc_common::differential::Check;
+use aoc_common::fuzz::Target;
+use aoc_common::{Frame, Generator, Part, Result, Rng, Solver};
+
+pub const DAYS: u32 = 18;
+
+#[derive(Clone, Copy)]
+pub struct Options {
+    pub preamble: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            preamble: day9::PREAMBLE,
+        }
+    }
+}
+
+pub fn run(day: u32, lines: &[String], part: Part, options: &Options) -> Result<()> {
+    match day {
+        1 => day1::run(lines, part),
+        2 => day2::run(lines, part),
+        3 => day3::run(lines, part),
+        4 => day4::run(lines, part),
+        5 => day5::run(lines, part),
+        6 => day6::run(lines, part),
+        7 => day7::run(lines, part),
+        8 => day8::run(lines, part),
+        9 => day9::run(lines, part, options.preamble),
+        10 => day10::run(lines, part),
+        11 => day11::run(lines, part),
+        12 => day12::run(lines, part),
+        13 => day13::run(lines, part),
+        14 => day14::run(lines, part),
+        15 => day15::run(lines, part),
+        16 => day16::run(lines, part),
+        17 => day17::run(lines, part),
+        18 => day18::run(lines, part),
+        _ => panic!("no such day: {}", day),
+    }
+}
+
+/// The pictures a day draws on its way to the answer for `part`, for the
+/// days that draw any.
+pub fn frames(day: u32, lines: &[String], part: Part) -> Option<Result<Vec<Frame>>> {
+    match (day, part) {
+        (11, _) => Some(day11::frames(lines, part)),
+        (17, Part::One) => Some(day17::frames(lines)),
+        _ => None,
+    }
+}
+
+pub fn solver(day: u32, options: &Options) -> Box<dyn Solver> {
+    match day {
+        1 => Box::new(day1::Day1),
+        2 => Box::new(day2::Day2),
+        3 => Box::new(day3::Day3),
+        4 => Box::new(day4::Day4),
+        5 => Box::new(day5::Day5),
+        6 => Box::new(day6::Day6),
+        7 => Box::new(day7::Day7),
+        8 => Box::new(day8::Day8),
+        9 => Box::new(day9::Day9 {
+            preamble: options.preamble,
+        }),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17),
+        18 => Box::new(day18::Day18),
+        _ => panic!("no such day: {}", day),
+    }
+}
+
+pub fn generator(day: u32, options: &Options) -> Box<dyn Generator> {
+    match day {
+        1 => Box::new(day1::Day1),
+        2 => Box::new(day2::Day2),
+        3 => Box::new(day3::Day3),
+        4 => Box::new(day4::Day4),
+        5 => Box::new(day5::Day5),
+        6 => Box::new(day6::Day6),
+        7 => Box::new(day7::Day7),
+        8 => Box::new(day8::Day8),
+        9 => Box::new(day9::Day9 {
+            preamble: options.preamble,
+        }),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17),
+        18 => Box::new(day18::Day18),
+        _ => panic!("no such day: {}", day),
+    }
+}
+
+/// Every differential check across the days.
+pub fn differentials() -> Vec<Box<dyn Check>> {
+    vec![
+        Box::new(day4::differential::SimpleVsStrict),
+        Box::new(day4::differential::StrictVsRules),
+        Box::new(day10::differential::ArrangementCount),
+        Box::new(day15::differential::SearchVsMemory),
+        Box::new(day18::differential::InOrder),
+        Box::new(day18::differential::AdditionsFirst),
+    ]
+}
+
+/// A fuzzing target that parses a day's input with the default options.
+pub fn fuzz_target(day: u32) -> Target {
+    Arc::new(move |lines: &[String]| {
+        let _ = solver(day, &Options::default()).parse_only(lines);
+    })
+}
+
+/// Small generated inputs for a day, for fuzzing to start from.
+pub fn fuzz_seeds(day: u32) -> Vec<Vec<u8>> {
+    let generator = generator(day, &Options::default());
+    (0..4)
+        .map(|seed| {
+            let size = generator.default_size().min(8);
+            let mut input = generator
+                .generate(&mut Rng::new(seed), size)
+                .lines
+                .join("\n");
+            input.push('\n');
+            input.into_bytes()
+        })
+        .collect()
+}
@@ -0,0 +1,20 @@
+use std::env;
+use std::path::PathBuf;
+
+/// Generates the C header from the exported functions and constants.
+///
+/// The header is written to `OUT_DIR`, not the source tree, and a test
+/// checks that the copy in `include/` matches it.
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("cbindgen.toml is not valid");
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("failed to generate the C header")
+        .write_to_file(out_dir.join("aoc2020.h"));
+}
@@ -0,0 +1,52 @@
+#ifndef AOC2020_H
+#define AOC2020_H
+
+/* Generated by cbindgen from src/lib.rs; do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// The answer is in the buffer.
+#define AOC_OK 0
+
+// The puzzle has no answer for this input.
+#define AOC_ERR_NO_SOLUTION 2
+
+// The day or part does not exist, or a pointer was null.
+#define AOC_ERR_USAGE 64
+
+// The input could not be parsed.
+#define AOC_ERR_BAD_INPUT 65
+
+// The solver panicked.
+#define AOC_ERR_PANIC 70
+
+// The answer does not fit in the buffer, which is left holding an error
+// message instead.
+#define AOC_ERR_BUFFER 71
+
+// The number of days there are solvers for.
+uint32_t aoc_days(void);
+
+// Solves one part of a day's puzzle.
+//
+// `input` holds `len` bytes of puzzle input, which must be UTF-8. On
+// success the answer is written to `out_buf` as a NUL-terminated string
+// and `AOC_OK` is returned. On failure an error code is returned and, if
+// `out_buf` has room, a NUL-terminated message saying what went wrong,
+// shortened to fit.
+//
+// Day 9 uses the puzzle's preamble of 25.
+//
+// # Safety
+//
+// `input` must point to `len` readable bytes, and `out_buf` to `out_len`
+// writable bytes. Either may be null when its length is zero.
+int aoc_solve(uint32_t day,
+              uint32_t part,
+              const uint8_t *input,
+              size_t len,
+              char *out_buf,
+              size_t out_len);
+
+#endif /* AOC2020_H */
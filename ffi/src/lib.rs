@@ -0,0 +1,139 @@
+//! Every day's solver behind a C ABI, for linking from C, Python and the
+//! like.
+//!
+//! The header, `include/aoc2020.h`, is generated from this file by the
+//! build script, and a test fails when the copy kept in the tree falls
+//! behind. Solver errors return the same codes that the `aoc` binary
+//! exits with, so a caller can treat them alike.
+
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use aoc_common::{normalise, Error, Part};
+use aoc_days::{Options, DAYS};
+
+/// The answer is in the buffer.
+pub const AOC_OK: c_int = 0;
+/// The puzzle has no answer for this input.
+pub const AOC_ERR_NO_SOLUTION: c_int = 2;
+/// The day or part does not exist, or a pointer was null.
+pub const AOC_ERR_USAGE: c_int = 64;
+/// The input could not be parsed.
+pub const AOC_ERR_BAD_INPUT: c_int = 65;
+/// The solver panicked.
+pub const AOC_ERR_PANIC: c_int = 70;
+/// The answer does not fit in the buffer, which is left holding an error
+/// message instead.
+pub const AOC_ERR_BUFFER: c_int = 71;
+
+/// The number of days there are solvers for.
+#[no_mangle]
+pub extern "C" fn aoc_days() -> u32 {
+    DAYS
+}
+
+/// Solves one part of a day's puzzle.
+///
+/// `input` holds `len` bytes of puzzle input, which must be UTF-8. On
+/// success the answer is written to `out_buf` as a NUL-terminated string
+/// and `AOC_OK` is returned. On failure an error code is returned and, if
+/// `out_buf` has room, a NUL-terminated message saying what went wrong,
+/// shortened to fit.
+///
+/// Day 9 uses the puzzle's preamble of 25.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, and `out_buf` to `out_len`
+/// writable bytes. Either may be null when its length is zero.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const u8,
+    len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    let out = if out_buf.is_null() || out_len == 0 {
+        &mut [][..]
+    } else {
+        slice::from_raw_parts_mut(out_buf as *mut u8, out_len)
+    };
+    if input.is_null() && len > 0 {
+        return fail(out, AOC_ERR_USAGE, "the input is null");
+    }
+    let input = if len == 0 {
+        &[][..]
+    } else {
+        slice::from_raw_parts(input, len)
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| answer(day, part, input))) {
+        Ok(Ok(answer)) => {
+            if write(out, &answer) {
+                AOC_OK
+            } else {
+                fail(
+                    out,
+                    AOC_ERR_BUFFER,
+                    &format!("the answer needs {} bytes", answer.len() + 1),
+                )
+            }
+        }
+        Ok(Err((code, message))) => fail(out, code, &message),
+        Err(_) => fail(out, AOC_ERR_PANIC, "the solver panicked"),
+    }
+}
+
+/// Works out the answer, or the code and message for the error.
+fn answer(day: u32, part: u32, input: &[u8]) -> Result<String, (c_int, String)> {
+    if !(1..=DAYS).contains(&day) {
+        return Err((AOC_ERR_USAGE, format!("there is no day {}", day)));
+    }
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err((AOC_ERR_USAGE, format!("there is no part {}", part))),
+    };
+
+    let input = std::str::from_utf8(input)
+        .map_err(|_| Error::bad_input("the input is not UTF-8"))
+        .map_err(|e| (e.exit_code(), e.to_string()))?;
+    let lines = normalise(input).0;
+
+    let answers = aoc_days::solver(day, &Options::default())
+        .solve(&lines, part)
+        .map_err(|e| (e.exit_code(), e.to_string()))?;
+
+    let answer = match part {
+        Part::Two => answers.part2,
+        _ => answers.part1,
+    };
+    Ok(answer.unwrap_or_default())
+}
+
+/// Copies `text` and a NUL into `out`, if they fit.
+fn write(out: &mut [u8], text: &str) -> bool {
+    if text.len() >= out.len() {
+        return false;
+    }
+
+    out[..text.len()].copy_from_slice(text.as_bytes());
+    out[text.len()] = 0;
+    true
+}
+
+/// Leaves as much of `message` in `out` as fits and returns `code`.
+fn fail(out: &mut [u8], code: c_int, message: &str) -> c_int {
+    if let Some(room) = out.len().checked_sub(1) {
+        let mut end = message.len().min(room);
+        while !message.is_char_boundary(end) {
+            end -= 1;
+        }
+        out[..end].copy_from_slice(&message.as_bytes()[..end]);
+        out[end] = 0;
+    }
+    code
+}
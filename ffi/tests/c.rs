@@ -0,0 +1,73 @@
+//! Builds the C test program against the shared library and runs it.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The directory the library was built into, which is the parent of the
+/// one this test runs from.
+fn library_directory() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent().unwrap().parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn the_c_interface_works() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let libraries = library_directory();
+    let program = env::temp_dir().join(format!("aoc2020-solve-{}", std::process::id()));
+
+    let compiler = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    let status = Command::new(&compiler)
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(manifest.join("include"))
+        .arg(manifest.join("tests").join("c").join("solve.c"))
+        .arg("-o")
+        .arg(&program)
+        .arg("-L")
+        .arg(&libraries)
+        .arg("-laoc2020")
+        .status()
+        .unwrap_or_else(|e| panic!("cannot run {}: {}", compiler, e));
+    assert!(status.success(), "the C test program did not build");
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &libraries)
+        .env("DYLD_LIBRARY_PATH", &libraries)
+        .output()
+        .unwrap();
+    let _ = std::fs::remove_file(&program);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+/// The header in `include/` is the one C programs build against, so it
+/// must match the one generated from the code. Run with `BLESS=1` to copy
+/// the generated header over it.
+#[test]
+fn the_header_is_up_to_date() {
+    let generated = Path::new(env!("OUT_DIR")).join("aoc2020.h");
+    let checked_in = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("include")
+        .join("aoc2020.h");
+
+    let expected = std::fs::read_to_string(&generated).unwrap();
+    if std::fs::read_to_string(&checked_in).ok().as_ref() == Some(&expected) {
+        return;
+    }
+
+    if env::var_os("BLESS").is_some() {
+        std::fs::write(&checked_in, expected).unwrap();
+    } else {
+        panic!(
+            "{} is out of date; run with BLESS=1 to update it",
+            checked_in.display()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * Exercises the C interface to the solvers. Exits with 0 if every check
+ * passed, printing each one that failed.
+ */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc2020.h"
+
+static const char INPUT[] = "1721\n979\n366\n299\n675\n1456\n";
+
+static int failures = 0;
+
+static void
+check(const char *what, int code, const char *out, int want_code,
+    const char *want_out)
+{
+	if (code != want_code || strcmp(out, want_out) != 0) {
+		printf("%s: got %d \"%s\", wanted %d \"%s\"\n", what, code, out,
+		    want_code, want_out);
+		failures++;
+	}
+}
+
+static int
+solve(uint32_t day, uint32_t part, const char *input, char *out,
+    size_t out_len)
+{
+	return aoc_solve(day, part, (const uint8_t *)input, strlen(input), out,
+	    out_len);
+}
+
+int
+main(void)
+{
+	char out[64];
+	char small[4];
+	int code;
+
+	if (aoc_days() != 18) {
+		printf("aoc_days: got %u, wanted 18\n", aoc_days());
+		failures++;
+	}
+
+	code = solve(1, 1, INPUT, out, sizeof(out));
+	check("day 1 part 1", code, out, AOC_OK, "514579");
+
+	code = solve(1, 2, INPUT, out, sizeof(out));
+	check("day 1 part 2", code, out, AOC_OK, "241861950");
+
+	code = solve(1, 1, "1721\n97x\n", out, sizeof(out));
+	check("bad input", code, out, AOC_ERR_BAD_INPUT,
+	    "2:1: \"97x\": invalid digit found in string");
+
+	code = solve(19, 1, INPUT, out, sizeof(out));
+	check("unknown day", code, out, AOC_ERR_USAGE, "there is no day 19");
+
+	code = solve(1, 3, INPUT, out, sizeof(out));
+	check("unknown part", code, out, AOC_ERR_USAGE, "there is no part 3");
+
+	code = solve(1, 1, INPUT, small, sizeof(small));
+	check("small buffer", code, small, AOC_ERR_BUFFER, "the");
+
+	code = aoc_solve(1, 1, NULL, 0, NULL, 0);
+	if (code != AOC_ERR_NO_SOLUTION) {
+		printf("no input: got %d, wanted %d\n", code,
+		    AOC_ERR_NO_SOLUTION);
+		failures++;
+	}
+
+	return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,12 @@
+//! A plugin that registers a day long after the event ends, which the
+//! runner turns down when loading it.
+
+use aoc_common::plugin::PART_ONE;
+
+aoc_common::export_plugin! {
+    day: 4_000_000_000,
+    name: "out-of-range",
+    parts: PART_ONE,
+    input: "one expense entry per line",
+    solution: day1::Day1,
+}
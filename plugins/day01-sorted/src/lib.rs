@@ -0,0 +1,66 @@
+//! Day 1 as a plugin, searching sorted entries from both ends instead of
+//! trying every combination.
+
+use std::cmp::Ordering;
+
+use aoc_common::plugin::{PART_ONE, PART_TWO};
+use aoc_common::{Error, Result, Solution};
+use day1::{Day1, Entries};
+
+const TARGET: u32 = 2020;
+
+/// Finds two of the sorted `values` that sum to `target`.
+fn pair(values: &[u32], target: u32) -> Option<(u32, u32)> {
+    let (mut low, mut high) = (0, values.len().checked_sub(1)?);
+
+    while low < high {
+        // A sum too large to hold is larger than any target.
+        let sum = values[low].checked_add(values[high]);
+        match sum.map_or(Ordering::Greater, |sum| sum.cmp(&target)) {
+            Ordering::Less => low += 1,
+            Ordering::Greater => high -= 1,
+            Ordering::Equal => return Some((values[low], values[high])),
+        }
+    }
+
+    None
+}
+
+pub struct Sorted;
+
+impl Solution for Sorted {
+    type Input = Vec<u32>;
+    type Part1 = Entries;
+    type Part2 = Entries;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let mut values = Day1.parse(lines)?;
+        values.sort_unstable();
+        Ok(values)
+    }
+
+    fn part1(&self, values: &Self::Input) -> Result<Self::Part1> {
+        pair(values, TARGET)
+            .map(|(x, y)| Entries(vec![x, y]))
+            .ok_or_else(|| Error::no_solution("no pair of entries sums to 2020"))
+    }
+
+    fn part2(&self, values: &Self::Input) -> Result<Self::Part2> {
+        values
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x <= TARGET)
+            .find_map(|(i, x)| {
+                pair(&values[i + 1..], TARGET - x).map(|(y, z)| Entries(vec![*x, y, z]))
+            })
+            .ok_or_else(|| Error::no_solution("no triple of entries sums to 2020"))
+    }
+}
+
+aoc_common::export_plugin! {
+    day: 1,
+    name: "sorted",
+    parts: PART_ONE | PART_TWO,
+    input: "one expense entry per line",
+    solution: Sorted,
+}